@@ -30,7 +30,11 @@ fn part2(input: Vec<i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -53,9 +57,7 @@ fn example2() {
     }
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 466);
-    assert_eq!(part2(input), 750);
+advent::tests! {
+    input: "2018/01",
+    default => [466, 750],
 }
@@ -48,7 +48,11 @@ fn diff(left: &str, right: &str) -> (usize, usize) {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -82,9 +86,7 @@ wvxyz",
     assert_eq!(part2(input), "fgij")
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 4712);
-    assert_eq!(part2(input), "lufjygedpvfbhftxiwnaorzmq");
+advent::tests! {
+    input: "2018/02",
+    default => [4712, "lufjygedpvfbhftxiwnaorzmq"],
 }
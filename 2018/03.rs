@@ -65,7 +65,11 @@ fn part2(claims: Vec<Claim>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -81,9 +85,7 @@ fn example() {
     assert_eq!(part2(input), 3);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 117948);
-    assert_eq!(part2(input), 567);
+advent::tests! {
+    input: "2018/03",
+    default => [117948, 567],
 }
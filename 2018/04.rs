@@ -84,7 +84,11 @@ fn part2(records: Vec<(DateTime<'_>, Event)>) -> i32 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -113,9 +117,7 @@ fn example() {
     assert_eq!(part1(input), 240)
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 12169);
-    assert_eq!(part2(input), 16164);
+advent::tests! {
+    input: "2018/04",
+    default => [12169, 16164],
 }
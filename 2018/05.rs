@@ -40,7 +40,11 @@ fn part2(suit: &str) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -51,9 +55,7 @@ fn example() {
     assert_eq!(part2(input), 4);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 10564);
-    assert_eq!(part2(input), 6336);
+advent::tests! {
+    input: "2018/05",
+    default => [10564, 6336],
 }
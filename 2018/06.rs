@@ -79,28 +79,22 @@ fn main() {
     let solution = advent::new(default_input)
         .part(part1)
         .part(|i| part2(i, 10_000))
+        .answers(answers())
         .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2018/06",
+    example(parse_input(EXAMPLE)) => [17, part2(32) => 16],
+    default => [4589, part2(10_000) => 40252],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 1, 1
 1, 6
 8, 3
 3, 4
 5, 5
-8, 9",
-    );
-    assert_eq!(part1(input.clone()), 17);
-    assert_eq!(part2(input, 32), 16);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 4589);
-    assert_eq!(part2(input, 10_000), 40252);
-}
+8, 9";
@@ -90,29 +90,23 @@ fn main() {
     let solution = advent::new(default_input)
         .part(part1)
         .part(|i| part2(i, 5, 60))
+        .answers(answers())
         .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2018/07",
+    example(parse_input(EXAMPLE)) => ["CABDFE", part2(2, 0) => 15],
+    default => ["CFMNLOAHRKPTWBJSYZVGUQXIDE", part2(5, 60) => 971],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 Step C must be finished before step A can begin.
 Step C must be finished before step F can begin.
 Step A must be finished before step B can begin.
 Step A must be finished before step D can begin.
 Step B must be finished before step E can begin.
 Step D must be finished before step E can begin.
-Step F must be finished before step E can begin.",
-    );
-    assert_eq!(part1(input.clone()), "CABDFE");
-    assert_eq!(part2(input, 2, 0), 15);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), "CFMNLOAHRKPTWBJSYZVGUQXIDE");
-    assert_eq!(part2(input, 5, 60), 971);
-}
+Step F must be finished before step E can begin.";
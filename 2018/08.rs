@@ -60,7 +60,11 @@ fn part2(data: Vec<usize>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -71,9 +75,7 @@ fn example() {
     assert_eq!(part2(input), 66);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 45868);
-    assert_eq!(part2(input), 19724);
+advent::tests! {
+    input: "2018/08",
+    default => [45868, 19724],
 }
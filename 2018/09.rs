@@ -39,7 +39,11 @@ fn part2((players, marbles): (usize, usize)) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -58,9 +62,7 @@ fn example() {
     }
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 429943);
-    assert_eq!(part2(input), 3615691746);
+advent::tests! {
+    input: "2018/09",
+    default => [429943, 3615691746_i64],
 }
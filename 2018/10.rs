@@ -70,14 +70,48 @@ fn part2(points: Vec<(Vector2, Vector2)>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
+advent::tests! {
+    input: "2018/10",
+    example(parse_input(EXAMPLE)) => [
         "\
+█░░░█░░███
+█░░░█░░░█░
+█░░░█░░░█░
+█████░░░█░
+█░░░█░░░█░
+█░░░█░░░█░
+█░░░█░░░█░
+█░░░█░░███
+",
+        3,
+    ],
+    default => [
+        "\
+█░░░░█░░█░░░░░░░██████░░░░██░░░░█░░░░█░░█████░░░░████░░░██████
+█░░░░█░░█░░░░░░░░░░░░█░░░█░░█░░░█░░░█░░░█░░░░█░░█░░░░█░░░░░░░█
+░█░░█░░░█░░░░░░░░░░░░█░░█░░░░█░░█░░█░░░░█░░░░█░░█░░░░░░░░░░░░█
+░█░░█░░░█░░░░░░░░░░░█░░░█░░░░█░░█░█░░░░░█░░░░█░░█░░░░░░░░░░░█░
+░░██░░░░█░░░░░░░░░░█░░░░█░░░░█░░██░░░░░░█████░░░█░░░░░░░░░░█░░
+░░██░░░░█░░░░░░░░░█░░░░░██████░░██░░░░░░█░░░░█░░█░░███░░░░█░░░
+░█░░█░░░█░░░░░░░░█░░░░░░█░░░░█░░█░█░░░░░█░░░░█░░█░░░░█░░░█░░░░
+░█░░█░░░█░░░░░░░█░░░░░░░█░░░░█░░█░░█░░░░█░░░░█░░█░░░░█░░█░░░░░
+█░░░░█░░█░░░░░░░█░░░░░░░█░░░░█░░█░░░█░░░█░░░░█░░█░░░██░░█░░░░░
+█░░░░█░░██████░░██████░░█░░░░█░░█░░░░█░░█████░░░░███░█░░██████
+",
+        10656,
+    ],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 position=< 9,  1> velocity=< 0,  2>
 position=< 7,  0> velocity=<-1,  0>
 position=< 3, -2> velocity=<-1,  1>
@@ -108,41 +142,4 @@ position=< 5,  0> velocity=< 1,  0>
 position=<-6,  0> velocity=< 2,  0>
 position=< 5,  9> velocity=< 1, -2>
 position=<14,  7> velocity=<-2,  0>
-position=<-3,  6> velocity=< 2, -1>",
-    );
-    assert_eq!(
-        part1(input.clone()),
-        "\
-█░░░█░░███
-█░░░█░░░█░
-█░░░█░░░█░
-█████░░░█░
-█░░░█░░░█░
-█░░░█░░░█░
-█░░░█░░░█░
-█░░░█░░███
-"
-    );
-    assert_eq!(part2(input), 3);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(
-        part1(input.clone()),
-        "\
-█░░░░█░░█░░░░░░░██████░░░░██░░░░█░░░░█░░█████░░░░████░░░██████
-█░░░░█░░█░░░░░░░░░░░░█░░░█░░█░░░█░░░█░░░█░░░░█░░█░░░░█░░░░░░░█
-░█░░█░░░█░░░░░░░░░░░░█░░█░░░░█░░█░░█░░░░█░░░░█░░█░░░░░░░░░░░░█
-░█░░█░░░█░░░░░░░░░░░█░░░█░░░░█░░█░█░░░░░█░░░░█░░█░░░░░░░░░░░█░
-░░██░░░░█░░░░░░░░░░█░░░░█░░░░█░░██░░░░░░█████░░░█░░░░░░░░░░█░░
-░░██░░░░█░░░░░░░░░█░░░░░██████░░██░░░░░░█░░░░█░░█░░███░░░░█░░░
-░█░░█░░░█░░░░░░░░█░░░░░░█░░░░█░░█░█░░░░░█░░░░█░░█░░░░█░░░█░░░░
-░█░░█░░░█░░░░░░░█░░░░░░░█░░░░█░░█░░█░░░░█░░░░█░░█░░░░█░░█░░░░░
-█░░░░█░░█░░░░░░░█░░░░░░░█░░░░█░░█░░░█░░░█░░░░█░░█░░░██░░█░░░░░
-█░░░░█░░██████░░██████░░█░░░░█░░█░░░░█░░█████░░░░███░█░░██████
-"
-    );
-    assert_eq!(part2(input), 10656);
-}
+position=<-3,  6> velocity=< 2, -1>";
@@ -63,7 +63,11 @@ fn part2(serial: i64) -> Show<[usize; 3]> {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -78,9 +82,6 @@ fn example() {
     }
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), Show([245, 14]));
-    assert_eq!(part2(input), Show([235, 206, 13]));
+advent::tests! {
+    default => [Show([245, 14]), Show([235, 206, 13])],
 }
@@ -73,7 +73,11 @@ fn part2((mut pots, rules): (BTreeSet<i32>, Vec<i32>)) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -101,9 +105,7 @@ initial state: #..#.#..##......###...###
     assert_eq!(part1(input), 325);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1_733);
-    assert_eq!(part2(input), 1_000_000_000_508);
+advent::tests! {
+    input: "2018/12",
+    default => [1_733, 1_000_000_000_508_i64],
 }
@@ -122,7 +122,11 @@ fn part2(tiles: HashMap<Vector2, char>) -> Vector2 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -155,9 +159,7 @@ fn example2() {
     assert_eq!(part2(input), vector![6, 4]);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), vector![118, 66]);
-    assert_eq!(part2(input), vector![70, 129]);
+advent::tests! {
+    input: "2018/13",
+    default => [vector![118, 66], vector![70, 129]],
 }
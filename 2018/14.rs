@@ -50,7 +50,11 @@ fn part2(input: &str) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -61,9 +65,6 @@ fn example() {
     assert_eq!(part1("2018"), 5941429882);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 1342316410);
-    assert_eq!(part2(input), 20235230);
+advent::tests! {
+    default => [1342316410, 20235230],
 }
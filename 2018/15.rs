@@ -200,7 +200,11 @@ fn part2(cave: HashMap<Vector2, Tile>) -> i32 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -301,9 +305,7 @@ fn example6() {
     assert_eq!(part2(input), 1140);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 201638);
-    assert_eq!(part2(input), 95764);
+advent::tests! {
+    input: "2018/15",
+    default => [201638, 95764],
 }
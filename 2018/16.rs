@@ -107,7 +107,11 @@ fn part2((samples, prog): (Vec<Sample>, Vec<[usize; 4]>)) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -142,9 +146,7 @@ After:  [3, 2, 2, 1]",
     assert_ne!(compute(s.before, (Op::Eqrr, [a, b, c])), s.after);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 636);
-    assert_eq!(part2(input), 674);
+advent::tests! {
+    input: "2018/16",
+    default => [636, 674],
 }
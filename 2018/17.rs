@@ -142,7 +142,11 @@ fn part2(map: HashMap<Vector2, Tile>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -163,9 +167,7 @@ y=13, x=498..504",
     assert_eq!(part2(input), 29);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 36790);
-    assert_eq!(part2(input), 30765);
+advent::tests! {
+    input: "2018/17",
+    default => [36790, 30765],
 }
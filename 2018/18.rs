@@ -112,7 +112,11 @@ fn part2<const N: usize>(mut grid: [u128; N]) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -134,9 +138,7 @@ fn example() {
     assert_eq!(part1(input), 1147);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 355918);
-    assert_eq!(part2(input), 202806);
+advent::tests! {
+    input: "2018/18",
+    default => [355918, 202806],
 }
@@ -75,13 +75,15 @@ fn part2(prog: Program) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1350);
-    assert_eq!(part2(input), 15844608);
+advent::tests! {
+    input: "2018/19",
+    default => [1350, 15844608],
 }
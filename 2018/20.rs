@@ -77,7 +77,11 @@ fn part2(regex: Vec<Segment>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -99,9 +103,7 @@ fn example3() {
     assert_eq!(part1(input), 18);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 4406);
-    assert_eq!(part2(input), 8468);
+advent::tests! {
+    input: "2018/20",
+    default => [4406, 8468],
 }
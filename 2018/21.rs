@@ -92,13 +92,15 @@ fn part2(_: Program) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 3345459);
-    assert_eq!(part2(input), 5857354);
+advent::tests! {
+    input: "2018/21",
+    default => [3345459, 5857354],
 }
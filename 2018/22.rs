@@ -107,7 +107,11 @@ fn part2((depth, target): (i64, Vector2)) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -118,9 +122,6 @@ fn example() {
     assert_eq!(part2(input), 45);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 11810);
-    assert_eq!(part2(input), 1015);
+advent::tests! {
+    default => [11810, 1015],
 }
@@ -51,7 +51,11 @@ fn part2(nanobots: Vec<(Vector3, i64)>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -84,9 +88,7 @@ pos=<10,10,10>, r=5",
     assert_eq!(part2(input), 36);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 674);
-    assert_eq!(part2(input), 129444177);
+advent::tests! {
+    input: "2018/23",
+    default => [674, 129444177],
 }
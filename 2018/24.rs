@@ -252,7 +252,11 @@ fn part2(groups: Vec<Group>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -269,9 +273,7 @@ Infection:
     assert_eq!(part2(input), 51);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 15392);
-    assert_eq!(part2(input), 1092);
+advent::tests! {
+    input: "2018/24",
+    default => [15392, 1092],
 }
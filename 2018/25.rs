@@ -50,7 +50,10 @@ fn part1(points: Vec<Vector4>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -120,8 +123,7 @@ fn example4() {
     assert_eq!(part1(input), 8);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 396);
+advent::tests! {
+    input: "2018/25",
+    default => [396],
 }
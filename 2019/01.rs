@@ -26,7 +26,11 @@ fn part2(masses: Vec<u64>) -> u64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -35,9 +39,7 @@ fn example() {
     assert_eq!(fuel_for_mass(100756), 50346);
 }
 
-#[test]
-fn default() {
-    let masses = default_input();
-    assert_eq!(part1(masses.clone()), 3432671);
-    assert_eq!(part2(masses), 5146132);
+advent::tests! {
+    input: "2019/01",
+    default => [3432671, 5146132],
 }
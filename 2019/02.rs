@@ -73,7 +73,11 @@ fn part2(input: Vec<usize>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -84,9 +88,7 @@ fn example() {
     assert_eq!(computer.mem[0], 3500);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 3850704);
-    assert_eq!(part2(input), 6718);
+advent::tests! {
+    input: "2019/02",
+    default => [3850704, 6718],
 }
@@ -65,7 +65,11 @@ fn part2([p1, p2]: [Path; 2]) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -102,9 +106,7 @@ U98,R91,D20,R16,D67,R40,U7,R15,U6,R7",
     assert_eq!(part2(input), 410);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 248);
-    assert_eq!(part2(input), 28580);
+advent::tests! {
+    input: "2019/03",
+    default => [248, 28580],
 }
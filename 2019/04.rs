@@ -47,13 +47,14 @@ fn part2(input: Range<u64>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 2220);
-    assert_eq!(part2(input), 1515);
+advent::tests! {
+    default => [2220, 1515],
 }
@@ -103,7 +103,11 @@ fn part2(input: Vec<i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -119,9 +123,7 @@ fn example() {
     assert_eq!(Computer::new(input).run(9), 1001);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 11933517);
-    assert_eq!(part2(input), 10428568);
+advent::tests! {
+    input: "2019/05",
+    default => [11933517, 10428568],
 }
@@ -31,7 +31,11 @@ fn part2(orbits: Orbits<'_>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -48,9 +52,7 @@ fn example2() {
     assert_eq!(part2(input), 4);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 106065);
-    assert_eq!(part2(input), 253);
+advent::tests! {
+    input: "2019/06",
+    default => [106065, 253],
 }
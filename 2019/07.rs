@@ -148,7 +148,11 @@ fn part2(input: Vec<i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -186,9 +190,7 @@ fn example2() {
     assert_eq!(part2(input), 18216);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 225056);
-    assert_eq!(part2(input), 14260332);
+advent::tests! {
+    input: "2019/07",
+    default => [225056, 14260332],
 }
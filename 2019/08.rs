@@ -67,7 +67,11 @@ fn part2(img: Image) -> String {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -77,8 +81,7 @@ fn example() {
     assert_eq!(part1(input), 1);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 1677);
+advent::tests! {
+    input: "2019/08",
+    default => [1677],
 }
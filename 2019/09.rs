@@ -16,7 +16,11 @@ fn part2(input: Vec<i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -40,9 +44,7 @@ fn example3() {
     assert_eq!(Computer::new(input).next().unwrap(), 1125899906842624)
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 2714716640);
-    assert_eq!(part2(input), 58879);
+advent::tests! {
+    input: "2019/09",
+    default => [2714716640_i64, 58879],
 }
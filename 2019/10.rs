@@ -56,7 +56,11 @@ fn part2(asteroids: HashSet<Vector2>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -151,9 +155,7 @@ fn example5() {
     assert_eq!(part2(input), 802);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 319);
-    assert_eq!(part2(input), 517);
+advent::tests! {
+    input: "2019/10",
+    default => [319, 517],
 }
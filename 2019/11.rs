@@ -78,16 +78,18 @@ fn part2(program: Vec<i64>) -> String {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1930);
-    assert_eq!(
-        part2(input),
+advent::tests! {
+    input: "2019/11",
+    default => [
+        1930,
         "\
 ░░██████░░░░████████░░██░░░░██░░██░░░░██░░████████░░░░████░░░░████████░░██░░░░██░░░░░░
 ░░██░░░░██░░██░░░░░░░░██░░██░░░░██░░░░██░░██░░░░░░░░██░░░░██░░░░░░░░██░░██░░░░██░░░░░░
@@ -95,6 +97,6 @@ fn default() {
 ░░██████░░░░██░░░░░░░░██░░██░░░░██░░░░██░░██░░░░░░░░██░░░░░░░░░░██░░░░░░██░░░░██░░░░░░
 ░░██░░░░░░░░██░░░░░░░░██░░██░░░░██░░░░██░░██░░░░░░░░██░░░░██░░██░░░░░░░░██░░░░██░░░░░░
 ░░██░░░░░░░░██░░░░░░░░██░░░░██░░██░░░░██░░████████░░░░████░░░░████████░░░░████░░░░░░░░
-"
-    )
+",
+    ],
 }
@@ -101,37 +101,27 @@ fn main() {
     let solution = advent::new(default_input)
         .part(|i| part1(i, 1000))
         .part(part2)
+        .answers(answers())
         .build();
     solution.cli()
 }
 
-#[test]
-fn example1() {
-    let input = parse_input(
-        "<x=-1, y=0, z=2>
-         <x=2, y=-10, z=-7>
-         <x=4, y=-8, z=8>
-         <x=3, y=5, z=-1>",
-    );
-    assert_eq!(part1(input.clone(), 10), 179);
-    assert_eq!(part2(input), 2772);
+advent::tests! {
+    example1(parse_input(EXAMPLE1)) => [part1(10) => 179, 2772],
+    example2(parse_input(EXAMPLE2)) => [part1(100) => 1940, 4686774924_i64],
+    default => [part1(1000) => 7988, 337721412394184_i64],
 }
 
-#[test]
-fn example2() {
-    let input = parse_input(
-        "<x=-8, y=-10, z=0>
-         <x=5, y=5, z=10>
-         <x=2, y=-7, z=3>
-         <x=9, y=-8, z=-3>",
-    );
-    assert_eq!(part1(input.clone(), 100), 1940);
-    assert_eq!(part2(input), 4686774924);
-}
+#[cfg(test)]
+const EXAMPLE1: &str = "\
+<x=-1, y=0, z=2>
+<x=2, y=-10, z=-7>
+<x=4, y=-8, z=8>
+<x=3, y=5, z=-1>";
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone(), 1000), 7988);
-    assert_eq!(part2(input), 337721412394184);
-}
+#[cfg(test)]
+const EXAMPLE2: &str = "\
+<x=-8, y=-10, z=0>
+<x=5, y=5, z=10>
+<x=2, y=-7, z=3>
+<x=9, y=-8, z=-3>";
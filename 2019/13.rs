@@ -72,13 +72,15 @@ fn part2(mut program: Vec<i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 398);
-    assert_eq!(part2(input), 19447);
+advent::tests! {
+    input: "2019/13",
+    default => [398, 19447],
 }
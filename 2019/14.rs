@@ -75,7 +75,11 @@ fn part2(formulae: Formulae<'_>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -168,9 +172,7 @@ fn example5() {
     assert_eq!(part2(input), 460664);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 158482);
-    assert_eq!(part2(input), 7993831);
+advent::tests! {
+    input: "2019/14",
+    default => [158482, 7993831],
 }
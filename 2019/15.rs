@@ -112,13 +112,15 @@ fn part2(program: Vec<i64>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 228);
-    assert_eq!(part2(input), 348);
+advent::tests! {
+    input: "2019/15",
+    default => [228, 348],
 }
@@ -74,7 +74,11 @@ fn part2(signal: Vec<i64>) -> String {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -102,9 +106,7 @@ fn example2() {
     assert_eq!(part2(input), "53553731");
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), "22122816");
-    assert_eq!(part2(input), "41402171");
+advent::tests! {
+    input: "2019/16",
+    default => ["22122816", "41402171"],
 }
@@ -212,13 +212,15 @@ fn part2(mut program: Vec<i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 2508);
-    assert_eq!(part2(input), 799463);
+advent::tests! {
+    input: "2019/17",
+    default => [2508, 799463],
 }
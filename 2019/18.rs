@@ -204,7 +204,11 @@ fn part2(mut map: HashMap<Vector2, Tile>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -326,9 +330,7 @@ fn example8() {
     assert_eq!(part2(input), 72);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 3646);
-    assert_eq!(part2(input), 1730);
+advent::tests! {
+    input: "2019/18",
+    default => [3646, 1730],
 }
@@ -48,13 +48,15 @@ fn part2(input: Vec<i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 192);
-    assert_eq!(part2(input), 8381082);
+advent::tests! {
+    input: "2019/19",
+    default => [192, 8381082],
 }
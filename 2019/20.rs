@@ -189,7 +189,11 @@ fn part2(map: HashMap<Vector2, Tile>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -308,9 +312,7 @@ RE....#.#                           #......RF
     assert_eq!(part2(input), 396);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 490);
-    assert_eq!(part2(input), 5648);
+advent::tests! {
+    input: "2019/20",
+    default => [490, 5648],
 }
@@ -110,13 +110,15 @@ RUN";
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 19356081);
-    assert_eq!(part2(input), 1141901823);
+advent::tests! {
+    input: "2019/21",
+    default => [19356081, 1141901823],
 }
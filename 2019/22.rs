@@ -156,7 +156,11 @@ fn part2(methods: Vec<Method>) -> i128 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -211,9 +215,7 @@ cut -1",
     assert_eq!(shuffle(methods, 10), [9, 2, 5, 8, 1, 4, 7, 0, 3, 6]);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 3293);
-    assert_eq!(part2(input), 54168121233945);
+advent::tests! {
+    input: "2019/22",
+    default => [3293, 54168121233945_i64],
 }
@@ -227,27 +227,21 @@ fn main() {
     let solution = advent::new(default_input)
         .part(part1)
         .part(|i| part2(i, 200))
+        .answers(answers())
         .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2019/24",
+    example(parse_input(EXAMPLE)) => [2129920, part2(10) => 99],
+    default => [28772955, part2(200) => 2023],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 ....#
 #..#.
 #..##
 ..#..
-#....",
-    );
-    assert_eq!(part1(input), 2129920);
-    assert_eq!(part2(input, 10), 99);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 28772955);
-    assert_eq!(part2(input, 200), 2023);
-}
+#....";
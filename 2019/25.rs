@@ -232,12 +232,14 @@ fn part1(input: Vec<i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 134349952);
+advent::tests! {
+    input: "2019/25",
+    default => [134349952],
 }
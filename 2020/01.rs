@@ -41,7 +41,11 @@ fn part2(numbers: Vec<i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -52,9 +56,7 @@ fn example() {
     assert_eq!(part2(input), 241861950);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 567171);
-    assert_eq!(part2(input), 212428694);
+advent::tests! {
+    input: "2020/01",
+    default => [567171, 212428694],
 }
@@ -45,7 +45,11 @@ fn part2(elements: Vec<Element<'_>>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -60,9 +64,8 @@ fn example() {
     assert_eq!(part1(input.clone()), 2);
     assert_eq!(part2(input), 1);
 }
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 383);
-    assert_eq!(part2(input), 272);
+
+advent::tests! {
+    input: "2020/02",
+    default => [383, 272],
 }
@@ -32,7 +32,11 @@ fn part2(map: HashSet<Vector2>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -56,9 +60,7 @@ fn example() {
     assert_eq!(part2(input), 336);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 282);
-    assert_eq!(part2(input), 958815792);
+advent::tests! {
+    input: "2020/03",
+    default => [282, 958815792],
 }
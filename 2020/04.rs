@@ -30,7 +30,11 @@ fn part2(input: Vec<String>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -96,9 +100,7 @@ iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719"#,
     assert_eq!(part2(input), 4);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 182);
-    assert_eq!(part2(input), 109);
+advent::tests! {
+    input: "2020/04",
+    default => [182, 109],
 }
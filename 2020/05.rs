@@ -39,7 +39,11 @@ fn part2(mut ids: Vec<i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -54,9 +58,7 @@ BBFFBBFRLL",
     assert_eq!(part1(input), 820);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 883);
-    assert_eq!(part2(input), 532);
+advent::tests! {
+    input: "2020/05",
+    default => [883, 532],
 }
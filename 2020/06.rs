@@ -38,7 +38,11 @@ fn part2(input: Vec<Vec<HashSet<char>>>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -65,9 +69,7 @@ b",
     assert_eq!(part2(input), 6);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 6587);
-    assert_eq!(part2(input), 3235);
+advent::tests! {
+    input: "2020/06",
+    default => [6587, 3235],
 }
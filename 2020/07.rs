@@ -47,7 +47,11 @@ fn part2(bags: Bags<'_>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -85,9 +89,7 @@ dark violet bags contain no other bags.
     assert_eq!(part2(input), 126);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 101);
-    assert_eq!(part2(input), 108636);
+advent::tests! {
+    input: "2020/07",
+    default => [101, 108636],
 }
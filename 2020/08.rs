@@ -82,7 +82,11 @@ fn part2(mut prog: Vec<(Op, i64)>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -104,9 +108,7 @@ acc +6",
     assert_eq!(part2(input), 8);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1584);
-    assert_eq!(part2(input), 920);
+advent::tests! {
+    input: "2020/08",
+    default => [1584, 920],
 }
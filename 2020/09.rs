@@ -51,21 +51,16 @@ fn main() {
     let solution = advent::new(default_input)
         .part(|i| part1(i, 25))
         .part(|i| part2(i, 70639851))
+        .answers(answers())
         .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input =
-        parse_input("35 20 15 25 47 40 62 55 65 95 102 117 150 182 127 219 299 277 309 576");
-    assert_eq!(part1(input.clone(), 5), 127);
-    assert_eq!(part2(input, 127), 62);
+advent::tests! {
+    input: "2020/09",
+    example(parse_input(EXAMPLE)) => [part1(5) => 127, part2(127) => 62],
+    default => [part1(25) => 70639851, part2(70639851) => 8249240],
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone(), 25), 70639851);
-    assert_eq!(part2(input, 70639851), 8249240);
-}
+#[cfg(test)]
+const EXAMPLE: &str = "35 20 15 25 47 40 62 55 65 95 102 117 150 182 127 219 299 277 309 576";
@@ -42,7 +42,11 @@ fn part2(joltages: Vec<i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -63,9 +67,7 @@ fn example2() {
     assert_eq!(part2(input), 19208);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1984);
-    assert_eq!(part2(input), 3543369523456);
+advent::tests! {
+    input: "2020/10",
+    default => [1984, 3543369523456_i64],
 }
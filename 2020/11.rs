@@ -115,7 +115,11 @@ fn part2(grid: Grid) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -138,9 +142,7 @@ L.LLLLL.LL",
     assert_eq!(part2(input), 26);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 2254);
-    assert_eq!(part2(input), 2004);
+advent::tests! {
+    input: "2020/11",
+    default => [2254, 2004],
 }
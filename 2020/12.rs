@@ -68,7 +68,11 @@ fn part2(instrs: Vec<Instr>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -79,9 +83,7 @@ fn example() {
     assert_eq!(part2(input), 286);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 845);
-    assert_eq!(part2(input), 27016);
+advent::tests! {
+    input: "2020/12",
+    default => [845, 27016],
 }
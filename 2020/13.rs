@@ -43,7 +43,11 @@ fn part2((_, mut bus_ids): (i64, Vec<(i64, i64)>)) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -54,9 +58,7 @@ fn example() {
     assert_eq!(part2(input), 1068781);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 3246);
-    assert_eq!(part2(input), 1010182346291467);
+advent::tests! {
+    input: "2020/13",
+    default => [3246, 1010182346291467_i64],
 }
@@ -98,7 +98,11 @@ fn part2(instrs: Vec<Instr<'_>>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -126,9 +130,7 @@ mem[26] = 1",
     assert_eq!(part2(input), 208);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 11884151942312);
-    assert_eq!(part2(input), 2625449018811);
+advent::tests! {
+    input: "2020/14",
+    default => [11884151942312_i64, 2625449018811_i64],
 }
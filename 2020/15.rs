@@ -44,7 +44,11 @@ fn part2(seq: &[usize]) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -64,9 +68,6 @@ fn example() {
     }
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 758);
-    assert_eq!(part2(input), 814);
+advent::tests! {
+    default => [758, 814],
 }
@@ -103,7 +103,11 @@ fn part2((rules, your, nearby): (Vec<Rule<'_>>, Ticket, Vec<Ticket>)) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -128,9 +132,7 @@ nearby tickets:
     assert_eq!(part2(input), 1);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 29019);
-    assert_eq!(part2(input), 517827547723);
+advent::tests! {
+    input: "2020/16",
+    default => [29019, 517827547723_i64],
 }
@@ -54,7 +54,11 @@ fn part2(input: HashSet<Vector<2>>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -65,9 +69,7 @@ fn example() {
     assert_eq!(part2(input), 848);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 242);
-    assert_eq!(part2(input), 2292);
+advent::tests! {
+    input: "2020/17",
+    default => [242, 2292],
 }
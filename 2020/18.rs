@@ -112,7 +112,11 @@ fn part2(input: Vec<Vec<Token>>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -136,9 +140,7 @@ fn example() {
     }
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 7293529867931);
-    assert_eq!(part2(input), 60807587180737);
+advent::tests! {
+    input: "2020/18",
+    default => [7293529867931_i64, 60807587180737_i64],
 }
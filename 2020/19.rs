@@ -109,7 +109,11 @@ fn part2((mut rules, messages): (HashMap<u8, Rule>, Vec<&str>)) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -187,9 +191,7 @@ aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba"#,
     assert_eq!(part2(input), 12);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 178);
-    assert_eq!(part2(input), 346);
+advent::tests! {
+    input: "2020/19",
+    default => [178, 346],
 }
@@ -273,14 +273,22 @@ fn part2(tiles: HashMap<i64, Tile>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2020/20",
+    example(parse_input(EXAMPLE)) => [20899048083289_i64, 273],
+    default => [5966506063747_i64, 1714],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 Tile 2311:
 ..##.#..#.
 ##..#.....
@@ -388,15 +396,4 @@ Tile 3079:
 ..#.###...
 ..#.......
 ..#.###...
-",
-    );
-    assert_eq!(part1(input.clone()), 20899048083289);
-    assert_eq!(part2(input), 273);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 5966506063747);
-    assert_eq!(part2(input), 1714);
-}
+";
@@ -96,29 +96,23 @@ fn part2(foods: Vec<Food<'_>>) -> String {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let foods = parse_input(
-        "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+advent::tests! {
+    input: "2020/21",
+    example(parse_input(EXAMPLE)) => [5, "mxmxvkd,sqjhc,fvjkl"],
+    default => [2098, "ppdplc,gkcplx,ktlh,msfmt,dqsbql,mvqkdj,ggsz,hbhsx"],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
 trh fvjkl sbzzf mxmxvkd (contains dairy)
 sqjhc fvjkl (contains soy)
 sqjhc mxmxvkd sbzzf (contains fish)
-",
-    );
-    assert_eq!(part1(foods.clone()), 5);
-    assert_eq!(part2(foods), "mxmxvkd,sqjhc,fvjkl");
-}
-
-#[test]
-fn default() {
-    let foods = default_input();
-    assert_eq!(part1(foods.clone()), 2098);
-    assert_eq!(
-        part2(foods),
-        "ppdplc,gkcplx,ktlh,msfmt,dqsbql,mvqkdj,ggsz,hbhsx"
-    );
-}
+";
@@ -114,7 +114,11 @@ fn part2(decks: [VecDeque<usize>; 2]) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -133,9 +137,7 @@ fn example2() {
     part2([VecDeque::from([43, 19]), VecDeque::from([2, 29, 14])]);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 34566);
-    assert_eq!(part2(input), 31854);
+advent::tests! {
+    input: "2020/22",
+    default => [34566, 31854],
 }
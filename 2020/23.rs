@@ -82,7 +82,11 @@ fn part2(cups: Vec<usize>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -93,9 +97,6 @@ fn example() {
     assert_eq!(part2(input), 149245887792);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), "49725386");
-    assert_eq!(part2(input), 538935646702);
+advent::tests! {
+    default => ["49725386", 538935646702_i64],
 }
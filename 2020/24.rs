@@ -84,7 +84,11 @@ fn part2(input: Vec<Vec<Vector2>>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -116,9 +120,7 @@ wseweeenwnesenwwwswnew",
     assert_eq!(part2(input), 2208);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 438);
-    assert_eq!(part2(input), 4038);
+advent::tests! {
+    input: "2020/24",
+    default => [438, 4038],
 }
@@ -31,7 +31,10 @@ fn part1((card_public, door_public): (u64, u64)) -> u64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -40,8 +43,6 @@ fn example() {
     assert_eq!(part1((5764801, 17807724)), 14897079);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 12929);
+advent::tests! {
+    default => [12929],
 }
@@ -29,7 +29,11 @@ fn part2(input: Vec<i64>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -40,9 +44,7 @@ fn example() {
     assert_eq!(part2(input), 5);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1448);
-    assert_eq!(part2(input), 1471);
+advent::tests! {
+    input: "2021/01",
+    default => [1448, 1471],
 }
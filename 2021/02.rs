@@ -50,7 +50,11 @@ fn part2(input: Vec<(Command, i64)>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -69,9 +73,7 @@ forward 2",
     assert_eq!(part2(input), 900);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1654760);
-    assert_eq!(part2(input), 1956047400);
+advent::tests! {
+    input: "2021/02",
+    default => [1654760, 1956047400],
 }
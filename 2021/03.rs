@@ -59,14 +59,19 @@ fn main() {
     let solution = advent::new(default_input)
         .part(|i| part1(i, 12))
         .part(|i| part2(i, 12))
+        .answers(answers())
         .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2021/03",
+    example(parse_input(EXAMPLE)) => [part1(5) => 198, part2(5) => 230],
+    default => [part1(12) => 3958484, part2(12) => 1613181],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 00100
 11110
 10110
@@ -78,15 +83,4 @@ fn example() {
 10000
 11001
 00010
-01010",
-    );
-    assert_eq!(part1(input.clone(), 5), 198);
-    assert_eq!(part2(input, 5), 230);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone(), 12), 3958484);
-    assert_eq!(part2(input, 12), 1613181);
-}
+01010";
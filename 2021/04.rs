@@ -83,7 +83,11 @@ fn part2((draws, mut boards): (Vec<i64>, Vec<Board>)) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 #[test]
@@ -114,9 +118,7 @@ fn example() {
     assert_eq!(part2(input), 1924);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 55770);
-    assert_eq!(part2(input), 2980);
+advent::tests! {
+    input: "2021/04",
+    default => [55770, 2980],
 }
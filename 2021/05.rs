@@ -49,7 +49,11 @@ fn part2(input: Vec<(Vector2, Vector2)>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -72,9 +76,7 @@ fn example() {
     assert_eq!(part2(input), 12);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 7269);
-    assert_eq!(part2(input), 21140);
+advent::tests! {
+    input: "2021/05",
+    default => [7269, 21140],
 }
@@ -34,7 +34,11 @@ fn part2(fish: Vec<usize>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -45,9 +49,7 @@ fn example() {
     assert_eq!(part2(input), 26984457539);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 360610);
-    assert_eq!(part2(input), 1631629590423);
+advent::tests! {
+    input: "2021/06",
+    default => [360610, 1631629590423_i64],
 }
@@ -33,7 +33,11 @@ fn part2(crabs: Vec<i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 #[test]
@@ -43,9 +47,7 @@ fn example() {
     assert_eq!(part2(input), 168);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 331067);
-    assert_eq!(part2(input), 92881128);
+advent::tests! {
+    input: "2021/07",
+    default => [331067, 92881128],
 }
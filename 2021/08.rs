@@ -61,7 +61,11 @@ fn part2(wires: Vec<(Vec<u32>, Vec<u32>)>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -84,9 +88,7 @@ gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
     assert_eq!(part2(input), 61229);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 534);
-    assert_eq!(part2(input), 1070188);
+advent::tests! {
+    input: "2021/08",
+    default => [534, 1070188],
 }
@@ -52,7 +52,11 @@ fn part2(map: HashMap<Vector2, i64>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -70,9 +74,7 @@ fn example() {
     assert_eq!(part2(input), 1134);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 423);
-    assert_eq!(part2(input), 1198704);
+advent::tests! {
+    input: "2021/09",
+    default => [423, 1198704],
 }
@@ -76,7 +76,11 @@ fn part2(input: &str) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -97,9 +101,7 @@ fn example() {
     assert_eq!(part2(input), 288957);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 367059);
-    assert_eq!(part2(input), 1952146692);
+advent::tests! {
+    input: "2021/10",
+    default => [367059, 1952146692],
 }
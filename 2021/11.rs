@@ -69,7 +69,11 @@ fn part2(mut map: HashMap<Vector2, i64>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -92,9 +96,7 @@ fn example() {
     assert_eq!(part2(input), 195);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1793);
-    assert_eq!(part2(input), 247);
+advent::tests! {
+    input: "2021/11",
+    default => [1793, 247],
 }
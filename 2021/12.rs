@@ -53,7 +53,11 @@ fn part2(graph: Graph<'_>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -119,9 +123,7 @@ start-RW",
     assert_eq!(part2(input), 3509);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 3761);
-    assert_eq!(part2(input), 99138);
+advent::tests! {
+    input: "2021/12",
+    default => [3761, 99138],
 }
@@ -69,14 +69,41 @@ fn part2((mut dots, folds): (HashSet<Vector2>, Vec<Fold>)) -> String {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
+advent::tests! {
+    input: "2021/13",
+    example(parse_input(EXAMPLE)) => [
+        17,
         "\
+██████████
+██░░░░░░██
+██░░░░░░██
+██░░░░░░██
+██████████
+",
+    ],
+    default => [
+        687,
+        "\
+████████░░░░████░░░░██░░░░██░░░░████░░░░██░░░░██░░██████░░░░████████░░░░████░░
+██░░░░░░░░██░░░░██░░██░░██░░░░██░░░░██░░██░░██░░░░██░░░░██░░░░░░░░██░░██░░░░██
+██████░░░░██░░░░░░░░████░░░░░░██░░░░░░░░████░░░░░░██████░░░░░░░░██░░░░██░░░░░░
+██░░░░░░░░██░░████░░██░░██░░░░██░░░░░░░░██░░██░░░░██░░░░██░░░░██░░░░░░██░░████
+██░░░░░░░░██░░░░██░░██░░██░░░░██░░░░██░░██░░██░░░░██░░░░██░░██░░░░░░░░██░░░░██
+██░░░░░░░░░░██████░░██░░░░██░░░░████░░░░██░░░░██░░██████░░░░████████░░░░██████
+",
+    ],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 6,10
 0,14
 9,10
@@ -97,34 +124,4 @@ fn example() {
 9,0
 
 fold along y=7
-fold along x=5",
-    );
-    assert_eq!(part1(input.clone()), 17);
-    assert_eq!(
-        part2(input),
-        "\
-██████████
-██░░░░░░██
-██░░░░░░██
-██░░░░░░██
-██████████
-"
-    );
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 687);
-    assert_eq!(
-        part2(input),
-        "\
-████████░░░░████░░░░██░░░░██░░░░████░░░░██░░░░██░░██████░░░░████████░░░░████░░
-██░░░░░░░░██░░░░██░░██░░██░░░░██░░░░██░░██░░██░░░░██░░░░██░░░░░░░░██░░██░░░░██
-██████░░░░██░░░░░░░░████░░░░░░██░░░░░░░░████░░░░░░██████░░░░░░░░██░░░░██░░░░░░
-██░░░░░░░░██░░████░░██░░██░░░░██░░░░░░░░██░░██░░░░██░░░░██░░░░██░░░░░░██░░████
-██░░░░░░░░██░░░░██░░██░░██░░░░██░░░░██░░██░░██░░░░██░░░░██░░██░░░░░░░░██░░░░██
-██░░░░░░░░░░██████░░██░░░░██░░░░████░░░░██░░░░██░░██████░░░░████████░░░░██████
-"
-    );
-}
+fold along x=5";
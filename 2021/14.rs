@@ -75,7 +75,11 @@ fn part2((template, rules): (&str, Rules)) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -106,9 +110,7 @@ CN -> C",
     assert_eq!(part2(input), 2188189693529);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 2937);
-    assert_eq!(part2(input), 3390034818249);
+advent::tests! {
+    input: "2021/14",
+    default => [2937, 3390034818249_i64],
 }
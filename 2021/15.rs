@@ -62,7 +62,11 @@ fn part2(input: HashMap<Vector2, i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -85,9 +89,7 @@ fn example() {
     assert_eq!(part2(input), 315);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 366);
-    assert_eq!(part2(input), 2829);
+advent::tests! {
+    input: "2021/15",
+    default => [366, 2829],
 }
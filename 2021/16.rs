@@ -108,7 +108,11 @@ fn part2(bits: Vec<u8>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -176,9 +180,7 @@ fn example5() {
     assert_eq!(part2(parse_input("9C0141080250320F1802104A08")), 1);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 934);
-    assert_eq!(part2(input), 912901337844);
+advent::tests! {
+    input: "2021/16",
+    default => [934, 912901337844_i64],
 }
@@ -63,7 +63,11 @@ fn part2(target: Target) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -81,9 +85,6 @@ fn example2() {
     assert_eq!(part2(input), 820);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 6786);
-    assert_eq!(part2(input), 2313);
+advent::tests! {
+    default => [6786, 2313],
 }
@@ -160,7 +160,11 @@ fn part2(nodes: Vec<Node>) -> u32 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -204,9 +208,7 @@ fn example2() {
     assert_eq!(part2(input), 3993);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 3551);
-    assert_eq!(part2(input), 4555);
+advent::tests! {
+    input: "2021/18",
+    default => [3551, 4555],
 }
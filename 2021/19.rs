@@ -76,13 +76,11 @@ fn solve(mut scans: VecDeque<Vec<Vector3>>) -> (HashSet<Vector3>, Vec<Vector3>)
     (beacons, scanners)
 }
 
-fn part1(scans: VecDeque<Vec<Vector3>>) -> usize {
-    let (beacons, _) = solve(scans);
+fn part1((beacons, _): (HashSet<Vector3>, Vec<Vector3>)) -> usize {
     beacons.len()
 }
 
-fn part2(scans: VecDeque<Vec<Vector3>>) -> i64 {
-    let (_, scanners) = solve(scans);
+fn part2((_, scanners): (HashSet<Vector3>, Vec<Vector3>)) -> i64 {
     scanners
         .into_iter()
         .array_combinations()
@@ -92,14 +90,24 @@ fn part2(scans: VecDeque<Vec<Vector3>>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .solve(solve)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2021/19",
+    solve: solve,
+    default => [405, 12306],
+    example(parse_input(EXAMPLE)) => [79, 3621],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 --- scanner 0 ---
 404,-588,-901
 528,-643,409
@@ -235,15 +243,4 @@ fn example() {
 839,-516,451
 891,-625,532
 -652,-548,-490
-30,-46,-14",
-    );
-    assert_eq!(part1(input.clone()), 79);
-    assert_eq!(part2(input), 3621);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 405);
-    assert_eq!(part2(input), 12306);
-}
+30,-46,-14";
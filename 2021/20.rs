@@ -61,7 +61,11 @@ fn part2((alg, image): (Vec<bool>, Image)) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -79,9 +83,7 @@ fn example() {
     assert_eq!(part2(input), 3351);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 5475);
-    assert_eq!(part2(input), 17548);
+advent::tests! {
+    input: "2021/20",
+    default => [5475, 17548],
 }
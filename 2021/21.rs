@@ -70,7 +70,11 @@ fn part2([p1, p2]: [i64; 2]) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -81,9 +85,6 @@ fn example() {
     assert_eq!(part2(input), 444356092776315);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 926610);
-    assert_eq!(part2(input), 146854918035875);
+advent::tests! {
+    default => [926610, 146854918035875_i64],
 }
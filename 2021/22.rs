@@ -68,7 +68,11 @@ fn part2(steps: Vec<(bool, Cuboid)>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -185,9 +189,7 @@ off x=-93533..-4276,y=-16170..68771,z=-104985..-24507",
     assert_eq!(part2(input), 2758514936282235);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 590467);
-    assert_eq!(part2(input), 1225064738333321);
+advent::tests! {
+    input: "2021/22",
+    default => [590467, 1225064738333321_i64],
 }
@@ -217,7 +217,11 @@ fn part2(map: Map<2>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -235,9 +239,7 @@ fn example() {
     assert_eq!(part2(input), 44169);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 16157);
-    assert_eq!(part2(input), 43481);
+advent::tests! {
+    input: "2021/23",
+    default => [16157, 43481],
 }
@@ -128,24 +128,26 @@ fn verify(instrs: &[[i64; 3]], digits: [i64; 14]) {
     assert_eq!(z, 0, "digits `{digits:?}` are incorrect")
 }
 
-fn part1(instrs: Vec<[i64; 3]>) -> i64 {
-    let (_, max) = solve(instrs);
+fn part1((_, max): (i64, i64)) -> i64 {
     max
 }
 
-fn part2(instrs: Vec<[i64; 3]>) -> i64 {
-    let (min, _) = solve(instrs);
+fn part2((min, _): (i64, i64)) -> i64 {
     min
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .solve(solve)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 74929995999389);
-    assert_eq!(part2(input), 11118151637112);
+advent::tests! {
+    input: "2021/24",
+    solve: solve,
+    default => [74929995999389_i64, 11118151637112_i64],
 }
@@ -80,7 +80,10 @@ fn part1(mut map: HashMap<Vector2, Tile>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -101,8 +104,7 @@ v.v..>>v.v
     assert_eq!(part1(input), 58);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 426);
+advent::tests! {
+    input: "2021/25",
+    default => [426],
 }
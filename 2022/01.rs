@@ -30,7 +30,11 @@ fn part2(input: Vec<Vec<i64>>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -56,9 +60,7 @@ fn example() {
     assert_eq!(part2(input), 45000);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 68787);
-    assert_eq!(part2(input), 198041);
+advent::tests! {
+    input: "2022/01",
+    default => [68787, 198041],
 }
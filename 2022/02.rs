@@ -81,7 +81,11 @@ fn part2(rounds: Vec<[u32; 2]>) -> u32 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -96,9 +100,7 @@ C Z",
     assert_eq!(part2(input), 12);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 12645);
-    assert_eq!(part2(input), 11756);
+advent::tests! {
+    input: "2022/02",
+    default => [12645, 11756],
 }
@@ -45,7 +45,11 @@ fn part2(rucksacks: Vec<Vec<i64>>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -63,9 +67,7 @@ CrZsJsPPZsGzwwsLwLmpwMDw",
     assert_eq!(part2(input), 70);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 7850);
-    assert_eq!(part2(input), 2581);
+advent::tests! {
+    input: "2022/03",
+    default => [7850, 2581],
 }
@@ -32,7 +32,11 @@ fn part2(pairs: Vec<[i64; 4]>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -50,9 +54,7 @@ fn example() {
     assert_eq!(part2(input), 4);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 576);
-    assert_eq!(part2(input), 905);
+advent::tests! {
+    input: "2022/04",
+    default => [576, 905],
 }
@@ -74,7 +74,11 @@ fn part2((mut stacks, moves): (Vec<VecDeque<u8>>, Vec<Move>)) -> String {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -95,9 +99,7 @@ move 1 from 1 to 2",
     assert_eq!(part2(input), "MCD");
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), "FRDSQRRCD");
-    assert_eq!(part2(input), "HRFTQVWNN");
+advent::tests! {
+    input: "2022/05",
+    default => ["FRDSQRRCD", "HRFTQVWNN"],
 }
@@ -22,7 +22,11 @@ fn part2(input: &str) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -44,9 +48,7 @@ fn example2() {
     assert_eq!(part2("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 26);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 1343);
-    assert_eq!(part2(input), 2193);
+advent::tests! {
+    input: "2022/06",
+    default => [1343, 2193],
 }
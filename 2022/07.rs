@@ -79,7 +79,11 @@ fn part2(cmds: Vec<Command<'_>>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -115,9 +119,7 @@ $ ls
     assert_eq!(part2(input), 24933642);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1583951);
-    assert_eq!(part2(input), 214171);
+advent::tests! {
+    input: "2022/07",
+    default => [1583951, 214171],
 }
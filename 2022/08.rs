@@ -54,7 +54,11 @@ fn part2(map: HashMap<Vector2, u8>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -71,9 +75,7 @@ fn example() {
     assert_eq!(part2(input), 8);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1845);
-    assert_eq!(part2(input), 230112);
+advent::tests! {
+    input: "2022/08",
+    default => [1845, 230112],
 }
@@ -53,7 +53,11 @@ fn part2(cmds: Vec<(Vector2, i64)>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -87,9 +91,7 @@ U 20",
     assert_eq!(part2(input), 36);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 6470);
-    assert_eq!(part2(input), 2658);
+advent::tests! {
+    input: "2022/09",
+    default => [6470, 2658],
 }
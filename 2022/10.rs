@@ -70,18 +70,46 @@ fn part2(dxs: Vec<Instr>) -> String {
             s.push_str("  ")
         }
     }
-    s
+    s.lines().map(str::trim_end).join("\n")
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "addx 15
+advent::tests! {
+    input: "2022/10",
+    example(parse_input(EXAMPLE)) => [
+        13140,
+        "
+████    ████    ████    ████    ████    ████    ████    ████    ████    ████
+██████      ██████      ██████      ██████      ██████      ██████      ██████
+████████        ████████        ████████        ████████        ████████
+██████████          ██████████          ██████████          ██████████
+████████████            ████████████            ████████████            ████████
+██████████████              ██████████████              ██████████████
+",
+    ],
+    default => [
+        17840,
+        "
+████████    ████    ██          ████    ██    ██  ██        ██████      ████
+██        ██    ██  ██        ██    ██  ██    ██  ██        ██    ██  ██    ██
+██████    ██    ██  ██        ██        ██    ██  ██        ██    ██  ██
+██        ████████  ██        ██  ████  ██    ██  ██        ██████    ██  ████
+██        ██    ██  ██        ██    ██  ██    ██  ██        ██        ██    ██
+████████  ██    ██  ████████    ██████    ████    ████████  ██          ██████
+",
+    ],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "addx 15
 addx -11
 addx 6
 addx -3
@@ -226,40 +254,4 @@ addx -6
 addx -11
 noop
 noop
-noop",
-    );
-    assert_eq!(part1(input.clone()), 13140);
-    assert_eq!(
-        trim_ends(part2(input)),
-        "
-████    ████    ████    ████    ████    ████    ████    ████    ████    ████
-██████      ██████      ██████      ██████      ██████      ██████      ██████
-████████        ████████        ████████        ████████        ████████
-██████████          ██████████          ██████████          ██████████
-████████████            ████████████            ████████████            ████████
-██████████████              ██████████████              ██████████████
-"
-    );
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 17840);
-    assert_eq!(
-        trim_ends(part2(input)),
-        "
-████████    ████    ██          ████    ██    ██  ██        ██████      ████
-██        ██    ██  ██        ██    ██  ██    ██  ██        ██    ██  ██    ██
-██████    ██    ██  ██        ██        ██    ██  ██        ██    ██  ██
-██        ████████  ██        ██  ████  ██    ██  ██        ██████    ██  ████
-██        ██    ██  ██        ██    ██  ██    ██  ██        ██        ██    ██
-████████  ██    ██  ████████    ██████    ████    ████████  ██          ██████
-"
-    );
-}
-
-#[cfg(test)]
-fn trim_ends(s: String) -> String {
-    s.lines().map(|line| line.trim_end()).join("\n")
-}
+noop";
@@ -104,7 +104,11 @@ fn part2(monkeys: Vec<Monkey>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -143,9 +147,7 @@ Monkey 3:
     assert_eq!(part2(input), 2713310158);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 66802);
-    assert_eq!(part2(input), 21800916620);
+advent::tests! {
+    input: "2022/11",
+    default => [66802, 21800916620_i64],
 }
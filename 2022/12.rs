@@ -55,7 +55,11 @@ fn part2((map, _, end): (HashMap<Vector2, u8>, Vector2, Vector2)) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -72,9 +76,7 @@ abdefghi",
     assert_eq!(part2(input), 29);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 420);
-    assert_eq!(part2(input), 414);
+advent::tests! {
+    input: "2022/12",
+    default => [420, 414],
 }
@@ -86,7 +86,11 @@ fn part2(mut packets: Vec<Packet>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -121,9 +125,7 @@ fn example() {
     assert_eq!(part2(input), 140);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 5557);
-    assert_eq!(part2(input), 22425);
+advent::tests! {
+    input: "2022/13",
+    default => [5557, 22425],
 }
@@ -66,7 +66,11 @@ fn part2(cave: HashSet<Vector2>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -80,9 +84,7 @@ fn example() {
     assert_eq!(part2(input), 93);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 774);
-    assert_eq!(part2(input), 22499);
+advent::tests! {
+    input: "2022/14",
+    default => [774, 22499],
 }
@@ -132,14 +132,19 @@ fn main() {
     let solution = advent::new(default_input)
         .part(|i| part1(i, 2_000_000))
         .part(|i| part2(i, 4_000_000))
+        .answers(answers())
         .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+advent::tests! {
+    input: "2022/15",
+    example(parse_input(EXAMPLE)) => [part1(10) => 26, part2(20) => 56000011],
+    default => [part1(2_000_000) => 5073496, part2(4_000_000) => 13081194638237_i64],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
 Sensor at x=9, y=16: closest beacon is at x=10, y=16
 Sensor at x=13, y=2: closest beacon is at x=15, y=3
 Sensor at x=12, y=14: closest beacon is at x=10, y=16
@@ -152,15 +157,4 @@ Sensor at x=20, y=14: closest beacon is at x=25, y=17
 Sensor at x=17, y=20: closest beacon is at x=21, y=22
 Sensor at x=16, y=7: closest beacon is at x=15, y=3
 Sensor at x=14, y=3: closest beacon is at x=15, y=3
-Sensor at x=20, y=1: closest beacon is at x=15, y=3",
-    );
-    assert_eq!(part1(input.clone(), 10), 26);
-    assert_eq!(part2(input, 20), 56000011);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone(), 2_000_000), 5073496);
-    assert_eq!(part2(input, 4_000_000), 13081194638237);
-}
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
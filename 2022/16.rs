@@ -143,7 +143,11 @@ fn part2(valves: Vec<Valve>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -165,9 +169,7 @@ Valve JJ has flow rate=21; tunnel leads to valve II",
     assert_eq!(part2(input), 1707);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1896);
-    assert_eq!(part2(input), 2576);
+advent::tests! {
+    input: "2022/16",
+    default => [1896, 2576],
 }
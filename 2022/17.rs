@@ -108,7 +108,11 @@ fn part2(jets: Vec<Vector2>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -119,9 +123,7 @@ fn example() {
     assert_eq!(part2(input), 1514285714288);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 3211);
-    assert_eq!(part2(input), 1589142857183);
+advent::tests! {
+    input: "2022/17",
+    default => [3211, 1589142857183_i64],
 }
@@ -74,7 +74,11 @@ fn part2(cubes: HashSet<Vector3>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -99,9 +103,7 @@ fn example() {
     assert_eq!(part2(input), 58);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 4504);
-    assert_eq!(part2(input), 2556);
+advent::tests! {
+    input: "2022/18",
+    default => [4504, 2556],
 }
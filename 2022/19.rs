@@ -116,7 +116,11 @@ fn part2(blueprints: Vec<Blueprint>) -> i16 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -128,9 +132,7 @@ Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsid
     assert_eq!(part1(input), 33);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1389);
-    assert_eq!(part2(input), 3003);
+advent::tests! {
+    input: "2022/19",
+    default => [1389, 3003],
 }
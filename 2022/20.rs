@@ -35,7 +35,11 @@ fn part2(file: Vec<i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -46,9 +50,7 @@ fn example() {
     assert_eq!(part2(input), 1623178306);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 4914);
-    assert_eq!(part2(input), 7973051839072);
+advent::tests! {
+    input: "2022/20",
+    default => [4914, 7973051839072_i64],
 }
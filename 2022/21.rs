@@ -116,7 +116,11 @@ fn part2(mut monkeys: HashMap<&str, Action<'_>>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -143,9 +147,7 @@ hmdt: 32",
     assert_eq!(part2(input), 301);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 24947355373338);
-    assert_eq!(part2(input), 3876907167495);
+advent::tests! {
+    input: "2022/21",
+    default => [24947355373338_i64, 3876907167495_i64],
 }
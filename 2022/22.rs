@@ -180,7 +180,11 @@ fn part2((map, instrs): (HashMap<Vector2, Tile>, Vec<Instr>)) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -206,14 +210,13 @@ fn example() {
     assert_eq!(part1(input), 6032);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 189140);
-    assert_eq!(part2(input), 115063);
+advent::tests! {
+    input: "2022/22",
+    default => [189140, 115063],
 }
 
 #[test]
+#[cfg_attr(not(advent_input = "2022/22"), ignore = "missing input")]
 fn test_wrap_cube() {
     let inv = |d| match d {
         Right => Left,
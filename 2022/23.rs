@@ -78,7 +78,11 @@ fn part2(grove: HashSet<Vector2>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -97,9 +101,7 @@ fn example1() {
     assert_eq!(part2(input), 20);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 3849);
-    assert_eq!(part2(input), 995);
+advent::tests! {
+    input: "2022/23",
+    default => [3849, 995],
 }
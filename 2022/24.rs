@@ -97,7 +97,11 @@ fn part2((start, end, cycle, maps): (Vector2, Vector2, i64, HashSet<Vector3>)) -
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -115,9 +119,7 @@ fn example() {
     assert_eq!(part2(input), 54);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 279);
-    assert_eq!(part2(input), 762);
+advent::tests! {
+    input: "2022/24",
+    default => [279, 762],
 }
@@ -41,14 +41,16 @@ fn part1(input: Vec<i64>) -> String {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), "2=-0=01----22-0-1-10");
+advent::tests! {
+    input: "2022/25",
+    default => ["2=-0=01----22-0-1-10"],
 }
 
 #[test]
@@ -41,7 +41,11 @@ fn part2(calibration: &str) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -69,9 +73,7 @@ zoneight234
     assert_eq!(part2(input), 281);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 55130);
-    assert_eq!(part2(input), 54985);
+advent::tests! {
+    input: "2023/01",
+    default => [55130, 54985],
 }
@@ -56,28 +56,25 @@ fn part2(games: Vec<(i64, Vec<Vector3>)>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2023/02",
+    example(parse_input(EXAMPLE)) => [8, 2286],
+    default => [1734, 70387],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
-",
-    );
-    assert_eq!(part1(input.clone()), 8);
-    assert_eq!(part2(input), 2286);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1734);
-    assert_eq!(part2(input), 70387);
-}
+";
@@ -78,14 +78,22 @@ fn part2(engine: HashMap<Vector2, Tile>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2023/03",
+    example(parse_input(EXAMPLE)) => [4361, 467835],
+    default => [550064, 85010461],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 467..114..
 ...*......
 ..35..633.
@@ -95,15 +103,4 @@ fn example() {
 ..592.....
 ......755.
 ...$.*....
-.664.598..",
-    );
-    assert_eq!(part1(input.clone()), 4361);
-    assert_eq!(part2(input), 467835);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 550064);
-    assert_eq!(part2(input), 85010461);
-}
+.664.598..";
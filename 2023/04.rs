@@ -47,7 +47,7 @@ fn part1(input: Vec<(Vec<i64>, Vec<i64>)>) -> usize {
         .into_iter()
         .map(|(winning, mine)| {
             let n = intersection_count(&winning, &mine);
-            2_usize.pow((n - 1) as u32)
+            (1 << n) >> 1
         })
         .sum()
 }
@@ -69,28 +69,25 @@ fn part2(input: Vec<(Vec<i64>, Vec<i64>)>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2023/04",
+    example(parse_input(EXAMPLE)) => [13, 30],
+    default => [25651, 19499881],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
 Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
 Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11",
-    );
-    assert_eq!(part1(input.clone()), 13);
-    assert_eq!(part2(input), 30);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 25651);
-    assert_eq!(part2(input), 19499881);
-}
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
@@ -118,14 +118,22 @@ fn part2((seeds, maps): (Vec<i64>, Vec<Vec<RangeMap>>)) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2023/05",
+    example(parse_input(EXAMPLE)) => [35, 46],
+    default => [331445006, 6472060],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 seeds: 79 14 55 13
 
 seed-to-soil map:
@@ -158,15 +166,4 @@ temperature-to-humidity map:
 
 humidity-to-location map:
 60 56 37
-56 93 4",
-    );
-    assert_eq!(part1(input.clone()), 35);
-    assert_eq!(part2(input), 46);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 331445006);
-    assert_eq!(part2(input), 6472060);
-}
+56 93 4";
@@ -28,24 +28,21 @@ fn part2(input: Vec<(i64, i64)>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
-Time:      7  15   30
-Distance:  9  40  200",
-    );
-    assert_eq!(part1(input.clone()), 288);
-    assert_eq!(part2(input), 71503);
+advent::tests! {
+    input: "2023/06",
+    example(parse_input(EXAMPLE)) => [288, 71503],
+    default => [1624896, 32583852],
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1624896);
-    assert_eq!(part2(input), 32583852);
-}
+#[cfg(test)]
+const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200";
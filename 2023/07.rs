@@ -87,27 +87,24 @@ fn part2(hands: Vec<(Hand, i64)>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2023/07",
+    example(parse_input(EXAMPLE)) => [6440, 5905],
+    default => [246409899, 244848487],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 32T3K 765
 T55J5 684
 KK677 28
 KTJJT 220
-QQQJA 483",
-    );
-    assert_eq!(part1(input.clone()), 6440);
-    assert_eq!(part2(input), 5905);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 246409899);
-    assert_eq!(part2(input), 244848487);
-}
+QQQJA 483";
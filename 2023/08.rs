@@ -75,7 +75,11 @@ fn part2((instrs, nodes): (Vec<Direction>, HashMap<Node, [Node; 2]>)) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 #[test]
@@ -126,9 +130,7 @@ XXX = (XXX, XXX)",
     assert_eq!(part2(input), 6);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 21389);
-    assert_eq!(part2(input), 21083806112641);
+advent::tests! {
+    input: "2023/08",
+    default => [21389, 21083806112641_i64],
 }
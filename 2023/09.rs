@@ -47,25 +47,22 @@ fn part2(mut dataset: Vec<Vec<i64>>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
-0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45",
-    );
-    assert_eq!(part1(input.clone()), 114);
-    assert_eq!(part2(input.clone()), 2);
+advent::tests! {
+    input: "2023/09",
+    example(parse_input(EXAMPLE)) => [114, 2],
+    default => [2175229206_i64, 942],
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 2175229206);
-    assert_eq!(part2(input), 942);
-}
+#[cfg(test)]
+const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
@@ -80,7 +80,11 @@ fn part2(field: HashMap<Vector2, Tile>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -164,9 +168,7 @@ L7JLJL-JLJLJL--JLJ.L",
     assert_eq!(part2(input), 10);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 6947);
-    assert_eq!(part2(input), 273);
+advent::tests! {
+    input: "2023/10",
+    default => [6947, 273],
 }
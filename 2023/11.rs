@@ -46,7 +46,11 @@ fn part2(space: HashSet<Vector2>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -70,9 +74,7 @@ fn example() {
     assert_eq!(solve(input, 100), 8410);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 9693756);
-    assert_eq!(part2(input), 717878258016);
+advent::tests! {
+    input: "2023/11",
+    default => [9693756, 717878258016_i64],
 }
@@ -167,28 +167,25 @@ fn part2(conditions: Vec<(Vec<Spring>, Vec<usize>)>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2023/12",
+    example(parse_input(EXAMPLE)) => [21, 525152],
+    default => [7173, 29826669191291_i64],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 ???.### 1,1,3
 .??..??...?##. 1,1,3
 ?#?#?#?#?#?#?#? 1,3,1,6
 ????.#...#... 4,1,1
 ????.######..#####. 1,6,5
-?###???????? 3,2,1",
-    );
-    assert_eq!(part1(input.clone()), 21);
-    assert_eq!(part2(input), 525152);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 7173);
-    assert_eq!(part2(input), 29826669191291);
-}
+?###???????? 3,2,1";
@@ -46,14 +46,22 @@ fn part2(input: Vec<HashSet<Vector2>>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2023/13",
+    example(parse_input(EXAMPLE)) => [405, 400],
+    default => [42974, 27587],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 #.##..##.
 ..#.##.#.
 ##......#
@@ -68,15 +76,4 @@ fn example() {
 #####.##.
 #####.##.
 ..##..###
-#....#..#",
-    );
-    assert_eq!(part1(input.clone()), 405);
-    assert_eq!(part2(input), 400);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 42974);
-    assert_eq!(part2(input), 27587);
-}
+#....#..#";
@@ -54,7 +54,7 @@ fn tilt<const D: usize>(mut platform: Platform<D>) -> Platform<D> {
                 continue;
             }
             let mut j = i;
-            while let Some(&Tile::Empty) = col.get(j - 1) {
+            while j > 0 && col[j - 1] == Tile::Empty {
                 j -= 1;
             }
             col.swap(i, j);
@@ -118,7 +118,11 @@ fn part2<const D: usize>(mut platform: Platform<D>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -141,9 +145,7 @@ O.#..O.#.#
     assert_eq!(part2(input), 64);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 108614);
-    assert_eq!(part2(input), 96447);
+advent::tests! {
+    input: "2023/14",
+    default => [108614, 96447],
 }
@@ -62,19 +62,19 @@ fn part2(init: &str) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
-#[test]
-fn example() {
-    let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-    assert_eq!(part1(input), 1320);
-    assert_eq!(part2(input), 145);
-}
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input), 510273);
-    assert_eq!(part2(input), 212449);
+advent::tests! {
+    input: "2023/15",
+    example(EXAMPLE) => [1320, 145],
+    default => [510273, 212449],
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
@@ -72,14 +72,22 @@ fn part2(layout: HashMap<Vector2, Tile>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        r#".|...\....
+advent::tests! {
+    input: "2023/16",
+    example(parse_input(EXAMPLE)) => [46, 51],
+    default => [7067, 7324],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = r#".|...\....
 |.-.\.....
 .....|-...
 ........|.
@@ -88,15 +96,4 @@ fn example() {
 ..../.\\..
 .-.-/..|..
 .|....-|.\
-..//.|...."#,
-    );
-    assert_eq!(part1(input.clone()), 46);
-    assert_eq!(part2(input), 51);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 7067);
-    assert_eq!(part2(input), 7324);
-}
+..//.|...."#;
@@ -84,14 +84,22 @@ fn part2(map: HashMap<Vector2, i64>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2023/17",
+    example(parse_input(EXAMPLE)) => [102, 94],
+    default => [1195, 1347],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 2413432311323
 3215453535623
 3255245654254
@@ -104,15 +112,4 @@ fn example() {
 4564679986453
 1224686865563
 2546548887735
-4322674655533",
-    );
-    assert_eq!(part1(input.clone()), 102);
-    assert_eq!(part2(input), 94);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1195);
-    assert_eq!(part2(input), 1347);
-}
+4322674655533";
@@ -76,14 +76,22 @@ fn part2(plan: Vec<(Vector2, Vector2)>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2023/18",
+    example(parse_input(EXAMPLE)) => [62, 952408144115_i64],
+    default => [48503, 148442153147147_i64],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 R 6 (#70c710)
 D 5 (#0dc571)
 L 2 (#5713f0)
@@ -97,15 +105,4 @@ U 2 (#caa171)
 R 2 (#7807d2)
 U 3 (#a77fa3)
 L 2 (#015232)
-U 2 (#7a21e3)",
-    );
-    assert_eq!(part1(input.clone()), 62);
-    assert_eq!(part2(input), 952408144115);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 48503);
-    assert_eq!(part2(input), 148442153147147);
-}
+U 2 (#7a21e3)";
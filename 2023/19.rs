@@ -175,7 +175,11 @@ fn part2((workflows, _): (HashMap<&str, Vec<Rule<'_>>>, Vec<Part>)) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -204,9 +208,8 @@ hdj{m>838:A,pv}
     assert_eq!(part1(input.clone()), 19114);
     assert_eq!(part2(input), 167409079868000);
 }
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 376008);
-    assert_eq!(part2(input), 124078207789312);
+
+advent::tests! {
+    input: "2023/19",
+    default => [376008, 124078207789312_i64],
 }
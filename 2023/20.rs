@@ -185,7 +185,11 @@ fn part2(machine: Machine<'_>) -> i64 {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
@@ -215,9 +219,7 @@ broadcaster -> a
     assert_eq!(part1(input), 11687500);
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 869395600);
-    assert_eq!(part2(input), 232605773145467);
+advent::tests! {
+    input: "2023/20",
+    default => [869395600, 232605773145467_i64],
 }
@@ -206,29 +206,26 @@ fn part2(bricks: Vec<Brick>) -> usize {
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2023/22",
+    example(parse_input(EXAMPLE)) => [5, 7],
+    default => [465, 79042],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 1,0,1~1,2,1
 0,0,2~2,0,2
 0,2,3~2,2,3
 0,0,4~0,2,4
 2,0,5~2,2,5
 0,1,6~2,1,6
-1,1,8~1,1,9",
-    );
-    assert_eq!(part1(input.clone()), 5);
-    assert_eq!(part2(input), 7);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 465);
-    assert_eq!(part2(input), 79042);
-}
+1,1,8~1,1,9";
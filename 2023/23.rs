@@ -145,14 +145,22 @@ fn part2((map, start, end): (HashMap<Vector2, Tile>, Vector2, Vector2)) -> usize
 }
 
 fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
     solution.cli()
 }
 
-#[test]
-fn example() {
-    let input = parse_input(
-        "\
+advent::tests! {
+    input: "2023/23",
+    example(parse_input(EXAMPLE)) => [94, 154],
+    default => [1930, 6230],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
 #.#####################
 #.......#########...###
 #######.#########.#.###
@@ -175,15 +183,4 @@ fn example() {
 #...#...#.#.>.>.#.>.###
 #.###.###.#.###.#.#v###
 #.....###...###...#...#
-#####################.#",
-    );
-    assert_eq!(part1(input.clone()), 94);
-    assert_eq!(part2(input), 154);
-}
-
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1930);
-    assert_eq!(part2(input), 6230);
-}
+#####################.#";
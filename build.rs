@@ -0,0 +1,83 @@
+//! Sets `advent_input = "{year}/{day}"` for each puzzle input that exists so
+//! that the tests for the default input are ignored when it is missing.
+//!
+//! The inputs are found the same way as at runtime, using `ADVENT_INPUT_DIR`
+//! and `ADVENT_INPUT_PATH` which `cargo advent` sets from its configuration.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+fn main() -> io::Result<()> {
+    println!("cargo:rerun-if-env-changed=ADVENT_INPUT_DIR");
+    println!("cargo:rerun-if-env-changed=ADVENT_INPUT_PATH");
+
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    let dir = match env::var_os("ADVENT_INPUT_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => manifest_dir.join("input"),
+    };
+    let template =
+        env::var("ADVENT_INPUT_PATH").unwrap_or_else(|_| String::from("{year}/{day}.txt"));
+
+    // A path that does not exist would rerun the build script on every build,
+    // so the directory is created to notice the first input being added
+    fs::create_dir_all(&dir)?;
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let mut files = Vec::new();
+    walk(&dir, &mut files)?;
+    for file in files {
+        let path = file
+            .strip_prefix(&dir)
+            .unwrap()
+            .to_string_lossy()
+            .replace('\\', "/");
+        if let Some((year, day)) = parse_path(&template, &path) {
+            println!("cargo:rustc-cfg=advent_input=\"{year}/{day}\"");
+        }
+    }
+    Ok(())
+}
+
+/// Appends every file in the directory and its subdirectories.
+fn walk(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            walk(&entry.path(), files)?;
+        } else {
+            files.push(entry.path());
+        }
+    }
+    Ok(())
+}
+
+/// Returns the year and day of the input at the path if it matches the path
+/// template, e.g. `2023/01.txt` for `{year}/{day}.txt`.
+fn parse_path<'a>(mut template: &str, mut path: &'a str) -> Option<(&'a str, &'a str)> {
+    let (mut year, mut day) = (None, None);
+    while let Some(c) = template.chars().next() {
+        let var = if template.starts_with("{year}") {
+            &mut year
+        } else if template.starts_with("{day}") {
+            &mut day
+        } else {
+            path = path.strip_prefix(c)?;
+            template = &template[c.len_utf8()..];
+            continue;
+        };
+        let n = path.bytes().take_while(u8::is_ascii_digit).count();
+        if n == 0 {
+            return None;
+        }
+        *var = Some(&path[..n]);
+        path = &path[n..];
+        template = &template[template.find('}').unwrap() + 1..];
+    }
+    match path.is_empty() {
+        true => Some((year?, day?)),
+        false => None,
+    }
+}
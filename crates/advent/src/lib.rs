@@ -28,12 +28,37 @@
 //! - Each part function takes `I` as an argument and returns something
 //!   implementing `Display`.
 //!
+//! If both parts are computed together then a shared step can be added using
+//! [`.solve(f)`][Builder::solve] and each part will take its output instead.
+//!
 //! Finally, `cli()` will instantiate a command line interface and run the
 //! program. Ordinary runs will run each part once and output the answers.
 //! Passing `--bench` to the program will perform a benchmark.
 //!
 //! ✨ That's all! You're free to structure your program however else you want.
 //!
+//! # Testing
+//!
+//! The [`tests!`] macro generates a test for each part for each input. The
+//! answers for the default input can also be verified at runtime by passing
+//! them to [`Builder::answers`].
+//!
+//! ```ignore
+//! fn main() {
+//!     let solution = advent::new(default_input)
+//!         .part(part1)
+//!         .part(part2)
+//!         .answers(answers())
+//!         .build();
+//!     solution.cli()
+//! }
+//!
+//! advent::tests! {
+//!     example(parse_input(EXAMPLE)) => [102, 94],
+//!     default => [1195, 1347],
+//! }
+//! ```
+//!
 //! # Features
 //!
 //! There are also some optional features which pull in some other crates.
@@ -41,7 +66,9 @@
 //! - **`festive`** enables some festive ascii art and changes the default
 //!   output to `--output festive`
 //! - **`json`** supports JSON output using `--output json`, useful for
-//!   collecting benchmark information
+//!   collecting benchmark information. The JSON summary is also written to the
+//!   file given by the `ADVENT_SUMMARY` environment variable, if set, whatever
+//!   the output style
 //! - **`prelude`** re-exports my prelude crate that can be imported using
 //!   ```
 //!   use advent::prelude::*;
@@ -59,21 +86,26 @@
 //! The command line interface looks like this.
 //!
 //! ```text
-//! Usage: example [--bench] [--output <output>]
+//! Usage: example [--bench] [--output <output>] [--stack-size <stack-size>]
 //!
 //! Run the program.
 //!
 //! Options:
 //!   --bench           whether to benchmark
 //!   --output          the output style
+//!   --stack-size      the stack size in bytes of the thread to run on
 //!   --help            display usage information
 //! ```
 //!
 //! The output style can be `boring`, `festive`, or `json`. To use json this
 //! crate requires the `json` feature to be set.
 //!
+//! Deeply recursive solutions can be run on a thread with a larger stack by
+//! passing `--stack-size` or by using [`Builder::stack_size`].
+//!
 
 mod human;
+mod macros;
 mod stats;
 pub mod summary;
 
@@ -87,27 +119,39 @@ use argh::FromArgs;
 pub use prelude;
 use yansi::Paint;
 
-use crate::summary::{Bench, Run, Summary};
+use crate::summary::{Bench, Kind, Run, Summary};
 
-type FnParse<'a, I> = Box<dyn Fn() -> I + 'a>;
-type FnPart<'a, I> = Box<dyn Fn(I) -> Box<dyn Display + 'a> + UnwindSafe + 'a>;
+type FnParse<'a, I> = Box<dyn Fn() -> I + Send + 'a>;
+type FnSolve<'a, I, S> = Box<dyn Fn(I) -> S + UnwindSafe + Send + 'a>;
+type FnPart<'a, S> = Box<dyn Fn(S) -> Box<dyn Display + 'a> + UnwindSafe + Send + 'a>;
 
 /// A builder for a [`Solution`].
+///
+/// `I` is the type of the parsed input and `S` is the type passed to each
+/// part, which is the same as `I` unless a [`solve`][Builder::solve] step is
+/// used.
 #[must_use]
-pub struct Builder<'a, I> {
+pub struct Builder<'a, I, S = I> {
     parse: Option<FnParse<'a, I>>,
     parse_ok: bool,
-    parts: Vec<(Option<String>, FnPart<'a, I>)>,
+    solve: Option<(FnSolve<'a, I, S>, bool)>,
+    parts: Vec<(Option<String>, FnPart<'a, S>)>,
+    answers: Vec<String>,
+    stack_size: Option<usize>,
 }
 
 /// A runner and benchmarker for an Advent of Code solution.
 ///
 /// Constructed using [`advent::new`][crate::new].
 #[must_use]
-pub struct Solution<'a, I> {
+pub struct Solution<'a, I, S = I> {
     parse: FnParse<'a, I>,
     parse_ok: bool,
-    parts: Vec<(String, FnPart<'a, I>)>,
+    solve: FnSolve<'a, I, S>,
+    solve_ok: bool,
+    parts: Vec<(String, FnPart<'a, S>)>,
+    answers: Vec<String>,
+    stack_size: Option<usize>,
 }
 
 /// Returns a new builder for a new Advent of Code run or benchmark using the
@@ -121,27 +165,73 @@ pub struct Solution<'a, I> {
 /// ```
 pub fn new<'a, F, I>(parse: F) -> Builder<'a, I>
 where
-    F: Fn() -> I + UnwindSafe + 'a,
+    F: Fn() -> I + UnwindSafe + Send + 'a,
 {
     Builder {
         parse: Some(Box::new(parse)),
         parse_ok: true,
+        solve: Some((Box::new(|i| i), false)),
         parts: Vec::new(),
+        answers: Vec::new(),
+        stack_size: None,
     }
 }
 
 impl<'a, I> Builder<'a, I>
 where
     I: Clone + UnwindSafe,
+{
+    /// Adds a step that is shared between the parts.
+    ///
+    /// The closure must take the parsed input as a parameter and return an
+    /// intermediate value which is passed to each part instead of the parsed
+    /// input. This is useful when both answers are computed at the same time.
+    /// The shared step is timed and reported separately from each part, as
+    /// `Solve`.
+    ///
+    /// This must be called before any parts are added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn parse_input() -> Vec<i64> { vec![1, 2, 3] }
+    /// # fn solve(input: Vec<i64>) -> (i64, i64) { (1, 3) }
+    /// let solution = advent::new(parse_input)
+    ///     .solve(solve)
+    ///     .part(|(min, _)| min)
+    ///     .part(|(_, max)| max)
+    ///     .build();
+    /// ```
+    pub fn solve<F, S>(&mut self, f: F) -> Builder<'a, I, S>
+    where
+        F: Fn(I) -> S + UnwindSafe + Send + 'a,
+    {
+        assert!(self.parts.is_empty(), "solve must be added before parts");
+        Builder {
+            parse: self.parse.take(),
+            parse_ok: self.parse_ok,
+            solve: Some((Box::new(f), true)),
+            parts: Vec::new(),
+            answers: std::mem::take(&mut self.answers),
+            stack_size: self.stack_size,
+        }
+    }
+}
+
+impl<'a, I, S> Builder<'a, I, S>
+where
+    I: Clone + UnwindSafe,
+    S: Clone + UnwindSafe,
 {
     /// Adds a part to run or benchmark.
     ///
-    /// The closure must take the parsed input as a parameter and return a
-    /// result that implements [`Display`].
+    /// The closure must take the parsed input, or the output of the shared
+    /// [`solve`][Builder::solve] step, as a parameter and return a result that
+    /// implements [`Display`].
     pub fn part<F, R>(&mut self, f: F) -> &mut Self
     where
         R: Display + 'a,
-        F: Fn(I) -> R + UnwindSafe + 'a,
+        F: Fn(S) -> R + UnwindSafe + Send + 'a,
     {
         self.parts.push((None, Box::new(move |i| Box::new(f(i)))));
         self
@@ -155,66 +245,132 @@ where
     pub fn named<F, R>(&mut self, name: &str, f: F) -> &mut Self
     where
         R: Display + 'a,
-        F: Fn(I) -> R + UnwindSafe + 'a,
+        F: Fn(S) -> R + UnwindSafe + Send + 'a,
     {
         let name = Some(String::from(name));
         self.parts.push((name, Box::new(move |i| Box::new(f(i)))));
         self
     }
 
+    /// Sets the expected answers for each part.
+    ///
+    /// When the solution is run, each part's result is compared against the
+    /// answer at the same position and the summary notes whether it was
+    /// correct. Answers are compared using their [`Display`] representation.
+    ///
+    /// The [`tests!`] macro defines an `answers()` function for the default
+    /// input which can be passed here.
+    pub fn answers<A>(&mut self, answers: impl IntoIterator<Item = A>) -> &mut Self
+    where
+        A: Display,
+    {
+        self.answers = answers.into_iter().map(|a| a.to_string()).collect();
+        self
+    }
+
+    /// Sets the stack size in bytes of the thread used to run the solution.
+    ///
+    /// When set, parsing and every part are run or benchmarked on a spawned
+    /// thread with the given stack size. This is useful for deeply recursive
+    /// solutions that would overflow the main thread's stack. It can also be
+    /// set at runtime using `--stack-size`.
+    pub fn stack_size(&mut self, bytes: usize) -> &mut Self {
+        self.stack_size = Some(bytes);
+        self
+    }
+
     /// Consumes the builder and produces a solution which can either be run or
     /// benchmarked.
-    pub fn build(&mut self) -> Solution<'a, I> {
+    pub fn build(&mut self) -> Solution<'a, I, S> {
         let parse = self.parse.take().expect("expected input");
         let parse_ok = self.parse_ok;
+        let (solve, solve_ok) = self.solve.take().expect("expected solve");
         let parts = self
             .parts
             .drain(..)
             .enumerate()
             .map(|(i, (name, f))| {
-                let name = name.unwrap_or_else(|| format!("Part {}", i + 1));
+                let name = name.unwrap_or_else(|| part_name(i));
                 (name, f)
             })
             .collect();
+        let answers = std::mem::take(&mut self.answers);
         Solution {
             parse,
             parse_ok,
+            solve,
+            solve_ok,
             parts,
+            answers,
+            stack_size: self.stack_size,
         }
     }
 }
 
-impl<'a, I> Solution<'a, I>
+impl<'a, I, S> Solution<'a, I, S>
 where
     I: Clone + UnwindSafe,
+    S: Clone + UnwindSafe,
 {
     /// Consumes this struct and runs the parts.
     pub fn run(self) -> Summary {
-        let Self { parse, parts, .. } = self;
+        let stack_size = self.stack_size;
+        with_stack_size(stack_size, move || self.run_impl())
+    }
+
+    fn run_impl(self) -> Summary {
+        let Self {
+            parse,
+            solve,
+            solve_ok,
+            parts,
+            answers,
+            ..
+        } = self;
 
         let mut runs = Vec::new();
 
-        // Time each part
+        // Time the shared step, if this panics then every part fails
         let input = (parse)();
-        for (name, f) in parts {
-            let input = input.clone();
+        let t0 = Instant::now();
+        let solved = std::panic::catch_unwind(move || solve(input)).ok();
+        let elapsed = t0.elapsed().as_secs_f64();
+        if solve_ok {
+            let result = match solved {
+                Some(_) => String::new(),
+                None => "🚨👻🚨".to_owned(),
+            };
+            runs.push(Run {
+                name: "Solve".to_owned(),
+                kind: Kind::Solve,
+                result,
+                elapsed,
+                expected: None,
+            });
+        }
+
+        // Time each part
+        for (i, (name, f)) in parts.into_iter().enumerate() {
+            let solved = solved.clone();
 
             let (result, elapsed) = {
                 let t0 = Instant::now();
-                let result = std::panic::catch_unwind(move || f(input));
+                let result = solved.and_then(|s| std::panic::catch_unwind(move || f(s)).ok());
                 let t1 = Instant::now();
                 let elapsed = (t1 - t0).as_secs_f64();
                 let result = match result {
-                    Ok(result) => result.to_string(),
-                    Err(_) => "🚨👻🚨".to_owned(),
+                    Some(result) => result.to_string(),
+                    None => "🚨👻🚨".to_owned(),
                 };
                 (result, elapsed)
             };
 
             runs.push(Run {
                 name,
+                kind: Kind::Part,
                 result,
                 elapsed,
+                expected: answers.get(i).cloned(),
             })
         }
 
@@ -224,10 +380,19 @@ where
     /// Consumes this struct and benchmarks the parts.
     #[must_use]
     pub fn bench(self) -> Summary {
+        let stack_size = self.stack_size;
+        with_stack_size(stack_size, move || self.bench_impl())
+    }
+
+    fn bench_impl(self) -> Summary {
         let Self {
             parse,
             parse_ok,
+            solve,
+            solve_ok,
             parts,
+            answers,
+            ..
         } = self;
 
         let mut benches = Vec::new();
@@ -237,27 +402,83 @@ where
             let stats = bench(&parse);
             benches.push(Bench {
                 name: "Parse".to_owned(),
+                kind: Kind::Parse,
                 stats,
             });
         }
 
-        // Benchmark each part
+        // Check the shared step once first, if this panics then every part fails
         let input = (parse)();
+        let solved = {
+            // The step is not called again if it panics
+            let solve = std::panic::AssertUnwindSafe(&solve);
+            let input = input.clone();
+            match std::panic::catch_unwind(move || solve(input)) {
+                Ok(solved) => solved,
+                Err(_) => {
+                    let runs = parts
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, (name, _))| Run {
+                            name,
+                            kind: Kind::Part,
+                            result: "🚨👻🚨".to_owned(),
+                            elapsed: 0.0,
+                            expected: answers.get(i).cloned(),
+                        })
+                        .collect();
+                    return Summary::Run(runs);
+                }
+            }
+        };
+
+        // Benchmark the shared step
+        if solve_ok {
+            let stats = bench_with_input(input.clone(), &solve);
+            benches.push(Bench {
+                name: "Solve".to_owned(),
+                kind: Kind::Solve,
+                stats,
+            });
+        }
+
+        // Benchmark each part
         for (name, f) in parts {
-            let stats = bench_with_input(input.clone(), &f);
-            benches.push(Bench { name, stats });
+            let stats = bench_with_input(solved.clone(), &f);
+            benches.push(Bench {
+                name,
+                kind: Kind::Part,
+                stats,
+            });
         }
 
         Summary::Bench(benches)
     }
 
     /// Parses the command line arguments and executes the run or benchmark.
-    pub fn cli(self) {
-        let Opt { bench, output } = argh::from_env();
+    pub fn cli(mut self) {
+        let Opt {
+            bench,
+            output,
+            stack_size,
+            list_parts,
+        } = argh::from_env();
+
+        // Used by the shell completions to describe the parts
+        if list_parts {
+            for (name, _) in &self.parts {
+                println!("{name}");
+            }
+            return;
+        }
+
+        if stack_size.is_some() {
+            self.stack_size = stack_size;
+        }
 
         #[cfg(feature = "festive")]
         if let Output::Festive = output {
-            println!("{}", ascii_art::fun());
+            print_banner();
         }
 
         let summary = if bench {
@@ -277,9 +498,88 @@ where
             Output::Json => summary.print_json().expect("failed to print json"),
             _ => summary.print(),
         }
+
+        #[cfg(feature = "json")]
+        if let Some(path) = std::env::var_os("ADVENT_SUMMARY") {
+            let file = std::fs::File::create(path).expect("failed to create summary file");
+            summary
+                .write_json(&mut std::io::BufWriter::new(file))
+                .expect("failed to write json");
+        }
+    }
+}
+
+/// Calls the function on a spawned thread with the given stack size, or on the
+/// current thread if no stack size is given.
+fn with_stack_size<F, R>(stack_size: Option<usize>, f: F) -> R
+where
+    F: FnOnce() -> R + Send,
+    R: Send,
+{
+    let Some(stack_size) = stack_size else {
+        return f();
+    };
+    std::thread::scope(|s| {
+        let handle = std::thread::Builder::new()
+            .name("advent".into())
+            .stack_size(stack_size)
+            .spawn_scoped(s, f)
+            .expect("failed to spawn thread");
+        match handle.join() {
+            Ok(summary) => summary,
+            Err(err) => std::panic::resume_unwind(err),
+        }
+    })
+}
+
+/// Returns the default name for the part at the given index.
+fn part_name(i: usize) -> String {
+    format!("Part {}", i + 1)
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use std::fmt::Display;
+
+    /// Checks the answer for a part using its [`Display`] representation, the
+    /// same as [`Builder::answers`][crate::Builder::answers], used by the
+    /// [`tests!`][crate::tests] macro.
+    #[track_caller]
+    pub fn check<E, A>(input: &str, i: usize, expected: E, actual: A)
+    where
+        E: Display,
+        A: Display,
+    {
+        let (expected, actual) = (expected.to_string(), actual.to_string());
+        if expected != actual {
+            panic!(
+                "{} answer for `{input}` input is incorrect\n  expected: {expected}\n    actual: {actual}",
+                crate::part_name(i)
+            );
+        }
     }
 }
 
+/// Prints the festive ascii art banner to stdout.
+#[cfg(feature = "festive")]
+pub fn print_banner() {
+    let stdout = &mut std::io::stdout().lock();
+    write_banner(stdout, summary::Style::Color).expect("failed to print banner")
+}
+
+/// Writes the festive ascii art banner to the given writer.
+#[cfg(feature = "festive")]
+pub fn write_banner<W>(w: &mut W, style: summary::Style) -> std::io::Result<()>
+where
+    W: std::io::Write,
+{
+    let image = match style {
+        summary::Style::Color => ascii_art::fun(),
+        summary::Style::Plain => ascii_art::fun().uncolored(),
+    };
+    writeln!(w, "{image}")
+}
+
 fn bench<F, O>(f: F) -> summary::Stats
 where
     F: Fn() -> O,
@@ -324,6 +624,12 @@ struct Opt {
     /// the output style (boring, festive, json)
     #[argh(option, default = "default_output()")]
     output: Output,
+    /// the stack size in bytes of the thread to run on
+    #[argh(option)]
+    stack_size: Option<usize>,
+    /// print the name of each part and exit
+    #[argh(switch, hidden_help)]
+    list_parts: bool,
 }
 
 #[cfg(feature = "festive")]
@@ -373,3 +679,106 @@ impl argh::FromArgValue for Output {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_solve() {
+        let summary = new(|| vec![3, 1, 2])
+            .solve(|v: Vec<i32>| (*v.iter().min().unwrap(), *v.iter().max().unwrap()))
+            .part(|(min, _)| min)
+            .part(|(_, max)| max)
+            .answers([1, 3])
+            .build()
+            .run();
+        let Summary::Run(runs) = summary else {
+            panic!("expected runs");
+        };
+        let names: Vec<_> = runs.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["Solve", "Part 1", "Part 2"]);
+        let kinds: Vec<_> = runs.iter().map(|r| r.kind).collect();
+        assert_eq!(kinds, [Kind::Solve, Kind::Part, Kind::Part]);
+        let results: Vec<_> = runs.iter().map(|r| r.result.as_str()).collect();
+        assert_eq!(results, ["", "1", "3"]);
+        assert!(runs[1..].iter().all(|r| r.is_correct() == Some(true)));
+    }
+
+    #[test]
+    fn run_without_solve() {
+        let summary = new(|| 1).part(|i| i).build().run();
+        let Summary::Run(runs) = summary else {
+            panic!("expected runs");
+        };
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].name, "Part 1");
+    }
+
+    #[test]
+    fn run_solve_panics() {
+        let summary = new(|| 1)
+            .solve(|_: i32| -> i32 { panic!("oops") })
+            .part(|i| i)
+            .part(|i| i + 1)
+            .build()
+            .run();
+        let Summary::Run(runs) = summary else {
+            panic!("expected runs");
+        };
+        assert_eq!(runs.len(), 3);
+        assert!(runs.iter().all(|r| r.result == "🚨👻🚨"));
+    }
+
+    #[test]
+    fn bench_solve_panics() {
+        let summary = new(|| 1)
+            .solve(|_: i32| -> i32 { panic!("oops") })
+            .part(|i| i)
+            .part(|i| i + 1)
+            .answers([1, 2])
+            .build()
+            .bench();
+        let Summary::Run(runs) = summary else {
+            panic!("expected runs");
+        };
+        let names: Vec<_> = runs.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["Part 1", "Part 2"]);
+        assert!(runs.iter().all(|r| r.is_correct() == Some(false)));
+    }
+
+    #[test]
+    fn run_stack_size() {
+        fn depth(n: u64) -> u64 {
+            let buf = std::hint::black_box([n; 16]);
+            match n {
+                0 => 0,
+                _ => 1 + depth(buf[0] - 1),
+            }
+        }
+
+        let summary = new(|| 100_000)
+            .part(depth)
+            .part(|_| -> u64 { panic!("oops") })
+            .stack_size(256 * 1024 * 1024)
+            .build()
+            .run();
+        let Summary::Run(runs) = summary else {
+            panic!("expected runs");
+        };
+        assert_eq!(runs[0].result, "100000");
+        assert_eq!(runs[1].result, "🚨👻🚨");
+    }
+
+    #[cfg(feature = "festive")]
+    #[test]
+    fn banner_plain() {
+        let mut buf = Vec::new();
+        write_banner(&mut buf, summary::Style::Plain).unwrap();
+        let banner = String::from_utf8(buf).unwrap();
+        assert!(!banner.contains('\u{1b}'));
+        let lines: Vec<_> = banner.trim_end_matches('\n').lines().collect();
+        assert_eq!(lines.len(), 19);
+        assert!(lines.iter().all(|line| line.chars().count() == 46));
+    }
+}
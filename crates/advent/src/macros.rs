@@ -0,0 +1,157 @@
+/// Generates a test for each part of a solution for each of the given inputs.
+///
+/// Each entry is the name of an input, followed by the expression producing
+/// the input and the expected answer for each part. The parts are expected to
+/// be the functions `part1` and `part2`. The `default` input may omit the
+/// expression, in which case `default_input()` is used.
+///
+/// ```ignore
+/// advent::tests! {
+///     input: "2023/01",
+///     default => [1195, 1347],
+///     example(parse_input(EXAMPLE)) => [102, 94],
+/// }
+/// ```
+///
+/// The `input` is the puzzle input used by `default_input()`. The tests for
+/// the `default` input are ignored unless `advent_input` is set to it, which
+/// the workspace build script does for each puzzle input that exists.
+///
+/// This expands to a module for each input containing a test for each part,
+/// e.g. `default::part1` and `example::part2`. On failure the part name and
+/// the expected and actual answers are shown.
+///
+/// Answers are compared using their [`Display`][std::fmt::Display]
+/// representation, so an integer answer outside the range of an `i32` needs a
+/// suffix, e.g. `952_408_144_115_i64`.
+///
+/// The `default` entry also defines an `answers()` function returning the
+/// expected answers so that they can be verified at runtime using
+/// [`Builder::answers`][crate::Builder::answers].
+///
+/// ```ignore
+/// let solution = advent::new(default_input)
+///     .part(part1)
+///     .part(part2)
+///     .answers(answers())
+///     .build();
+/// ```
+///
+/// If the solution uses a shared [`solve`][crate::Builder::solve] step then
+/// pass it first and it will be applied to each input before the parts.
+///
+/// ```ignore
+/// advent::tests! {
+///     input: "2023/01",
+///     solve: solve,
+///     default => [1195, 1347],
+/// }
+/// ```
+///
+/// If a part takes extra arguments, e.g. a size that differs between the
+/// example and the puzzle, then they can be given with the answer, and the part
+/// is called with the input followed by the arguments.
+///
+/// ```ignore
+/// advent::tests! {
+///     input: "2018/06",
+///     example(parse_input(EXAMPLE)) => [17, part2(32) => 16],
+///     default => [4589, part2(10_000) => 40252],
+/// }
+/// ```
+#[macro_export]
+macro_rules! tests {
+    (@entries $puzzle:tt $solve:tt) => {};
+
+    (@entries $puzzle:tt $solve:tt default => [$($answers:tt)+] $(, $($rest:tt)*)?) => {
+        $crate::tests!(@parts [answers $puzzle $solve default (default_input())] [] [0 part1 1 part2] $($answers)+);
+        $crate::tests!(@entries $puzzle $solve $($($rest)*)?);
+    };
+
+    (@entries $puzzle:tt $solve:tt $name:ident ($input:expr) => [$($answers:tt)+] $(, $($rest:tt)*)?) => {
+        $crate::tests!(@parts [[] $solve $name ($input)] [] [0 part1 1 part2] $($answers)+);
+        $crate::tests!(@entries $puzzle $solve $($($rest)*)?);
+    };
+
+    // Collects each answer as `{index test part [args] answer}`
+    (@parts [answers $($entry:tt)+] [$({$($part:tt)+})+] [$($names:tt)*] $(,)?) => {
+        $crate::tests!(@answers [$({$($part)+})+]);
+        $crate::tests!(@module $($entry)+ [$({$($part)+})+]);
+    };
+
+    (@parts [$($entry:tt)+] [$({$($part:tt)+})+] [$($names:tt)*] $(,)?) => {
+        $crate::tests!(@module $($entry)+ [$({$($part)+})+]);
+    };
+
+    (@parts $entry:tt [$($parts:tt)*] [$i:tt $test:ident $($names:tt)*] $part:ident ($($arg:expr),* $(,)?) => $answer:expr $(, $($rest:tt)*)?) => {
+        $crate::tests!(@parts $entry [$($parts)* {$i $test $part [$($arg),*] $answer}] [$($names)*] $($($rest)*)?);
+    };
+
+    (@parts $entry:tt [$($parts:tt)*] [$i:tt $test:ident $($names:tt)*] $answer:expr $(, $($rest:tt)*)?) => {
+        $crate::tests!(@parts $entry [$($parts)* {$i $test $test [] $answer}] [$($names)*] $($($rest)*)?);
+    };
+
+    (@answers [$({$i:tt $test:ident $part:ident [$($arg:expr),*] $answer:expr})+]) => {
+        /// Returns the expected answers for the default input.
+        #[allow(dead_code)]
+        fn answers() -> ::std::vec::Vec<::std::string::String> {
+            ::std::vec![$(::std::string::ToString::to_string(&$answer)),+]
+        }
+    };
+
+    (@module $puzzle:tt $solve:tt $name:ident ($input:expr) [$({$i:tt $test:ident $part:ident [$($arg:expr),*] $answer:expr})+]) => {
+        #[cfg(test)]
+        mod $name {
+            use super::*;
+
+            $(
+                $crate::tests! {
+                    @test $puzzle
+                    fn $test() {
+                        let actual = super::$part($crate::tests!(@solve $solve $input) $(, $arg)*);
+                        $crate::tests!(@check $name, $i, actual, $answer);
+                    }
+                }
+            )+
+        }
+    };
+
+    (@test [] $test:item) => {
+        #[test]
+        $test
+    };
+
+    (@test [$puzzle:literal] $test:item) => {
+        #[test]
+        #[cfg_attr(not(advent_input = $puzzle), ignore = "missing input")]
+        $test
+    };
+
+    (@solve [] $input:expr) => {
+        $input
+    };
+
+    (@solve [$solve:path] $input:expr) => {
+        $solve($input)
+    };
+
+    (@check $name:ident, $i:expr, $actual:expr, $expected:expr) => {
+        $crate::__private::check(stringify!($name), $i, $expected, $actual);
+    };
+
+    (input: $puzzle:literal, solve: $solve:path, $($entries:tt)*) => {
+        $crate::tests!(@entries [$puzzle] [$solve] $($entries)*);
+    };
+
+    (input: $puzzle:literal, $($entries:tt)*) => {
+        $crate::tests!(@entries [$puzzle] [] $($entries)*);
+    };
+
+    (solve: $solve:path, $($entries:tt)*) => {
+        $crate::tests!(@entries [] [$solve] $($entries)*);
+    };
+
+    ($($entries:tt)*) => {
+        $crate::tests!(@entries [] [] $($entries)*);
+    };
+}
@@ -1,4 +1,7 @@
-use yansi::Paint;
+use std::io;
+use std::io::Write;
+
+use yansi::{Condition, Paint, Painted};
 
 use crate::human;
 
@@ -12,6 +15,22 @@ pub enum Summary {
     Run(Vec<Run>),
 }
 
+/// What a run or benchmark measures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "json",
+    derive(serde::Serialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Kind {
+    /// Parsing the input.
+    Parse,
+    /// The step shared between the parts.
+    Solve,
+    /// A part of the puzzle.
+    Part,
+}
+
 /// The result of a benchmark.
 #[derive(Debug)]
 #[cfg_attr(feature = "json", derive(serde::Serialize))]
@@ -19,6 +38,8 @@ pub enum Summary {
 pub struct Bench {
     /// The name of the benchmark.
     pub name: String,
+    /// What the benchmark measures.
+    pub kind: Kind,
     /// The data for the benchmark.
     #[cfg_attr(feature = "json", serde(flatten))]
     pub stats: Stats,
@@ -31,10 +52,15 @@ pub struct Bench {
 pub struct Run {
     /// The name of the run.
     pub name: String,
+    /// What the run measures.
+    pub kind: Kind,
     /// The output of the run.
     pub result: String,
     /// How long this run took in seconds.
     pub elapsed: f64,
+    /// The expected output of the run, if known.
+    #[cfg_attr(feature = "json", serde(skip_serializing_if = "Option::is_none"))]
+    pub expected: Option<String>,
 }
 
 /// Data for a benchmark.
@@ -54,72 +80,317 @@ pub struct Stats {
     pub std_dev: f64,
 }
 
+/// How to render pretty output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Render using terminal colors and text attributes.
+    Color,
+    /// Render plain text without any escape sequences.
+    Plain,
+}
+
+impl Style {
+    pub(crate) fn paint<T>(self, p: Painted<T>) -> Painted<T> {
+        match self {
+            Self::Color => p,
+            Self::Plain => p.whenever(Condition::NEVER),
+        }
+    }
+}
+
+impl Run {
+    /// Returns whether the output matches the expected answer, or `None` if
+    /// the answer is not known.
+    pub fn is_correct(&self) -> Option<bool> {
+        self.expected.as_ref().map(|e| *e == self.result)
+    }
+}
+
 impl Summary {
     /// Pretty prints the summary to stdout.
     pub fn print(&self) {
+        self.write_pretty(&mut io::stdout().lock(), Style::Color)
+            .expect("failed to print summary")
+    }
+
+    /// Pretty prints the summary to the given writer.
+    pub fn write_pretty<W: Write>(&self, w: &mut W, style: Style) -> io::Result<()> {
         match self {
-            Self::Bench(parts) => print_bench_summary(parts),
-            Self::Run(parts) => print_run_summary(parts),
+            Self::Bench(parts) => write_bench_summary(w, style, parts),
+            Self::Run(parts) => write_run_summary(w, style, parts),
         }
     }
 
     /// Prints the summary as JSON to stdout.
     #[cfg(feature = "json")]
     pub fn print_json(&self) -> serde_json::Result<()> {
-        serde_json::to_writer(std::io::BufWriter::new(std::io::stdout()), self)
+        self.write_json(&mut io::BufWriter::new(io::stdout()))
+    }
+
+    /// Writes the summary as JSON to the given writer.
+    #[cfg(feature = "json")]
+    pub fn write_json<W: Write>(&self, w: &mut W) -> serde_json::Result<()> {
+        serde_json::to_writer(w, self)
     }
 }
 
-fn print_bench_summary(parts: &[Bench]) {
+fn write_bench_summary<W: Write>(w: &mut W, style: Style, parts: &[Bench]) -> io::Result<()> {
     for (i, part) in parts.iter().enumerate() {
-        let Bench { name, stats } = part;
+        let Bench { name, stats, .. } = part;
         if i != 0 {
-            println!();
+            writeln!(w)?;
         }
-        println!(
+        let samples = human::Samples::new(stats.samples);
+        writeln!(
+            w,
             "{}{:>width$}",
-            Paint::new(name).bold(),
-            Paint::fixed(&human::Samples::new(stats.samples), 245),
+            style.paint(Paint::new(name).bold()),
+            style.paint(Paint::fixed(&samples, 245)),
             width = 46 - name.chars().count(),
-        );
+        )?;
         let mean = human::Time::new(stats.mean);
         let std_dev = human::Time::with_scale(stats.std_dev, mean.scale());
         let min = human::Time::with_scale(stats.min, mean.scale());
         let max = human::Time::with_scale(stats.max, mean.scale());
-        println!(
+        writeln!(
+            w,
             "  Time ({} ± {}):       {:>9} ± {:>9}",
-            Paint::green("mean").bold(),
-            Paint::green("σ"),
-            Paint::green(&mean).bold(),
-            Paint::green(&std_dev),
-        );
-        println!(
+            style.paint(Paint::green("mean").bold()),
+            style.paint(Paint::green("σ")),
+            style.paint(Paint::green(&mean).bold()),
+            style.paint(Paint::green(&std_dev)),
+        )?;
+        writeln!(
+            w,
             "  Range ({} … {}):     {:>9} … {:>9}",
-            Paint::cyan("min"),
-            Paint::magenta("max"),
-            Paint::cyan(&min),
-            Paint::magenta(&max),
-        );
+            style.paint(Paint::cyan("min")),
+            style.paint(Paint::magenta("max")),
+            style.paint(Paint::cyan(&min)),
+            style.paint(Paint::magenta(&max)),
+        )?;
     }
+    Ok(())
 }
 
-fn print_run_summary(parts: &[Run]) {
+fn write_run_summary<W: Write>(w: &mut W, style: Style, parts: &[Run]) -> io::Result<()> {
     for (i, part) in parts.iter().enumerate() {
         let Run {
             name,
+            kind,
             result,
             elapsed,
+            expected,
         } = part;
         if i != 0 {
-            println!();
+            writeln!(w)?;
         }
         let width = 46_usize.saturating_sub(name.chars().count() + 2);
-        println!(
-            "{}: {:>width$}\n{}",
-            Paint::cyan(&name).bold(),
-            Paint::fixed(&format!("({})", human::Time::new(*elapsed)), 245),
-            Paint::new(&result).bold(),
+        let elapsed = format!("({})", human::Time::new(*elapsed));
+        writeln!(
+            w,
+            "{}: {:>width$}",
+            style.paint(Paint::cyan(&name).bold()),
+            style.paint(Paint::fixed(&elapsed, 245)),
             width = width,
+        )?;
+        // Only the parts have a result
+        if *kind != Kind::Part {
+            continue;
+        }
+        write!(w, "{}", style.paint(Paint::new(&result).bold()))?;
+        match expected {
+            Some(expected) if expected == result => {
+                writeln!(w, " {}", style.paint(Paint::green("✓")))?
+            }
+            Some(expected) => {
+                let note = format!("✗ expected {expected}");
+                writeln!(w, " {}", style.paint(Paint::red(&note)))?
+            }
+            None => writeln!(w)?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runs() -> Summary {
+        Summary::Run(vec![
+            Run {
+                name: "Part 1".into(),
+                kind: Kind::Part,
+                result: "1337".into(),
+                elapsed: 0.001234,
+                expected: None,
+            },
+            Run {
+                name: "Part 2".into(),
+                kind: Kind::Part,
+                result: "42".into(),
+                elapsed: 2.5,
+                expected: None,
+            },
+        ])
+    }
+
+    fn benches() -> Summary {
+        Summary::Bench(vec![
+            Bench {
+                name: "Parse".into(),
+                kind: Kind::Parse,
+                stats: Stats {
+                    samples: 1337,
+                    min: 0.000_012,
+                    max: 0.000_1,
+                    mean: 0.000_023_45,
+                    std_dev: 0.000_001_2,
+                },
+            },
+            Bench {
+                name: "Part 1".into(),
+                kind: Kind::Part,
+                stats: Stats {
+                    samples: 25,
+                    min: 0.18,
+                    max: 0.23,
+                    mean: 0.2,
+                    std_dev: 0.01,
+                },
+            },
+        ])
+    }
+
+    fn render(summary: &Summary, style: Style) -> String {
+        let mut buf = Vec::new();
+        summary.write_pretty(&mut buf, style).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn run_summary_plain() {
+        assert_eq!(
+            render(&runs(), Style::Plain),
+            "\
+Part 1:                             (1.234 ms)
+1337
+
+Part 2:                              (2.500 s)
+42
+"
+        );
+    }
+
+    #[test]
+    fn bench_summary_plain() {
+        assert_eq!(
+            render(&benches(), Style::Plain),
+            "\
+Parse                           1.337k samples
+  Time (mean ± σ):        23.45 µs ±  1.200 µs
+  Range (min … max):      12.00 µs …  100.0 µs
+
+Part 1                              25 samples
+  Time (mean ± σ):        200.0 ms ±  10.00 ms
+  Range (min … max):      180.0 ms …  230.0 ms
+"
+        );
+    }
+
+    #[test]
+    fn run_summary_answers() {
+        let summary = Summary::Run(vec![
+            Run {
+                name: "Part 1".into(),
+                kind: Kind::Part,
+                result: "1337".into(),
+                elapsed: 0.001234,
+                expected: Some("1337".into()),
+            },
+            Run {
+                name: "Part 2".into(),
+                kind: Kind::Part,
+                result: "42".into(),
+                elapsed: 2.5,
+                expected: Some("43".into()),
+            },
+        ]);
+        assert_eq!(
+            render(&summary, Style::Plain),
+            "\
+Part 1:                             (1.234 ms)
+1337 ✓
+
+Part 2:                              (2.500 s)
+42 ✗ expected 43
+"
+        );
+    }
+
+    #[test]
+    fn run_summary_solve() {
+        let summary = Summary::Run(vec![
+            Run {
+                name: "Solve".into(),
+                kind: Kind::Solve,
+                result: String::new(),
+                elapsed: 0.25,
+                expected: None,
+            },
+            Run {
+                name: "Part 1".into(),
+                kind: Kind::Part,
+                result: "7".into(),
+                elapsed: 0.001,
+                expected: None,
+            },
+        ]);
+        assert_eq!(
+            render(&summary, Style::Plain),
+            "\
+Solve:                              (250.0 ms)
+
+Part 1:                             (1.000 ms)
+7
+"
+        );
+    }
+
+    #[test]
+    fn run_summary_color() {
+        let summary = Summary::Run(vec![Run {
+            name: "Part 1".into(),
+            kind: Kind::Part,
+            result: "7".into(),
+            elapsed: 0.5,
+            expected: None,
+        }]);
+        assert_eq!(
+            render(&summary, Style::Color),
+            "\u{1b}[1;36mPart 1\u{1b}[0m: \u{1b}[38;5;245m                            (500.0 ms)\u{1b}[0m\n\u{1b}[1m7\u{1b}[0m\n"
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn run_summary_json() {
+        let mut buf = Vec::new();
+        runs().write_json(&mut buf).unwrap();
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            r#"{"runs":[{"name":"Part 1","kind":"part","result":"1337","elapsed":0.001234},{"name":"Part 2","kind":"part","result":"42","elapsed":2.5}]}"#
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn bench_summary_json() {
+        let mut buf = Vec::new();
+        benches().write_json(&mut buf).unwrap();
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            r#"{"benches":[{"name":"Parse","kind":"parse","samples":1337,"min":0.000012,"max":0.0001,"mean":0.00002345,"std_dev":1.2e-6},{"name":"Part 1","kind":"part","samples":25,"min":0.18,"max":0.23,"mean":0.2,"std_dev":0.01}]}"#
         );
     }
 }
@@ -25,6 +25,14 @@ impl Image {
     fn height(&self) -> usize {
         self.0.len()
     }
+
+    /// Removes the color from every pixel in the image.
+    pub fn uncolored(mut self) -> Self {
+        for pixel in self.0.iter_mut().flatten() {
+            pixel.color = None;
+        }
+        self
+    }
 }
 
 impl fmt::Display for Pixel {
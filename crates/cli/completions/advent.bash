@@ -0,0 +1,30 @@
+# Bash completions for `cargo advent`.
+#
+# Add the following to ~/.bashrc after the completions for `cargo` are set up.
+#
+#     eval "$(cargo advent completions bash)"
+#
+# The completions for any other `cargo` subcommand are left to the existing
+# completion function.
+
+# The completions for `cargo` are loaded lazily by bash-completion
+if ! complete -p cargo &>/dev/null && declare -F _completion_loader &>/dev/null; then
+    _completion_loader cargo
+fi
+if [[ -z $_cargo_advent_fallback ]]; then
+    _cargo_advent_fallback=$(complete -p cargo 2>/dev/null | sed -n 's/.* -F \([^ ]*\) .*/\1/p')
+fi
+
+_cargo_advent() {
+    if [[ ${COMP_WORDS[1]} != advent || $COMP_CWORD -lt 2 ]]; then
+        if [[ -n $_cargo_advent_fallback ]]; then
+            "$_cargo_advent_fallback" "$@"
+        fi
+        return
+    fi
+    local IFS=$'\n'
+    local words=("${COMP_WORDS[@]:2:COMP_CWORD-1}")
+    COMPREPLY=($(cargo advent completions --complete "${words[@]}" 2>/dev/null | cut -f1))
+}
+
+complete -o bashdefault -o default -F _cargo_advent cargo
@@ -0,0 +1,13 @@
+# Fish completions for `cargo advent`.
+#
+# Add the following to ~/.config/fish/config.fish
+#
+#     cargo advent completions fish | source
+
+function __cargo_advent_complete
+    set -l words (commandline -opc)[3..-1]
+    set -l current (commandline -ct)
+    cargo advent completions --complete $words "$current" 2>/dev/null
+end
+
+complete -c cargo -n '__fish_seen_subcommand_from advent' -f -a '(__cargo_advent_complete)'
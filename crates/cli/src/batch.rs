@@ -0,0 +1,593 @@
+//! Run, test or benchmark many solutions and summarize them in a table.
+
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::table::{Cell, Style, Table};
+use crate::{cargo_command, check_input, config, history, print, split_args, warning};
+
+/// The JSON output of a solution, see `advent::summary::Summary`.
+#[derive(Debug, Deserialize)]
+pub enum Summary {
+    #[serde(rename = "benches")]
+    Bench(Vec<Bench>),
+    #[serde(rename = "runs")]
+    Run(Vec<Run>),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Bench {
+    pub name: String,
+    #[serde(default)]
+    pub kind: Kind,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub std_dev: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Run {
+    pub name: String,
+    #[serde(default)]
+    pub kind: Kind,
+    pub result: String,
+    pub elapsed: f64,
+    pub expected: Option<String>,
+}
+
+/// What a run or benchmark measures, see `advent::summary::Kind`.
+///
+/// Summaries from before the kind was recorded only have parts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    Parse,
+    Solve,
+    #[default]
+    Part,
+}
+
+/// The result of a failed part.
+pub const FAILED: &str = "🚨👻🚨";
+
+/// A solution binary for a single puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+}
+
+/// The outcome for a single puzzle.
+#[derive(Debug)]
+enum Outcome {
+    Summary(Summary),
+    Test { passed: usize, failed: usize },
+    Failed,
+}
+
+impl Puzzle {
+    pub fn bin_name(&self) -> String {
+        format!("{:04}{:02}", self.year, self.day)
+    }
+}
+
+/// Runs each solution and prints a table of the answers.
+pub fn run(puzzles: &[Puzzle], args: &[String]) -> Result<()> {
+    let outcomes = execute(puzzles, args, false)?;
+    finish(&render_runs(&outcomes, Style::stdout()), &outcomes)
+}
+
+/// Benchmarks each solution and prints a table of the mean times.
+pub fn bench(puzzles: &[Puzzle], args: &[String]) -> Result<()> {
+    let outcomes = execute(puzzles, args, true)?;
+    let results: Vec<_> = outcomes
+        .iter()
+        .filter_map(|(p, outcome)| match outcome {
+            Outcome::Summary(Summary::Bench(benches)) => Some((*p, &benches[..])),
+            _ => None,
+        })
+        .collect();
+    history::record(&results)?;
+    finish(&render_benches(&outcomes, Style::stdout()), &outcomes)
+}
+
+/// Tests each solution and prints a table of the test results.
+pub fn test(puzzles: &[Puzzle], args: &[String]) -> Result<()> {
+    for p in puzzles {
+        check_input(p.year, p.day)?;
+    }
+
+    let bins: Vec<_> = puzzles.iter().map(Puzzle::bin_name).collect();
+    let args = [&config::get().cargo_args.value[..], args].concat();
+    cargo(["test", "--release", "--quiet", "--no-run"], &bins, &args)?;
+
+    let mut outcomes = Vec::new();
+    for (p, bin) in puzzles.iter().zip(&bins) {
+        print("Testing", format!("`{bin}`"));
+        let output = cargo_command()
+            .args(["test", "--release", "--quiet", "--bin", bin])
+            .args(&args)
+            .output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let outcome = match parse_test_result(&stdout) {
+            Some((passed, failed)) => Outcome::Test { passed, failed },
+            None => Outcome::Failed,
+        };
+        outcomes.push((*p, outcome));
+    }
+
+    finish(&render_tests(&outcomes, Style::stdout()), &outcomes)
+}
+
+/// Builds the solutions and then runs each one, collecting the JSON output.
+fn execute(puzzles: &[Puzzle], args: &[String], bench: bool) -> Result<Vec<(Puzzle, Outcome)>> {
+    for p in puzzles {
+        check_input(p.year, p.day)?;
+    }
+
+    let (cargo_args, bin_args) = split_args(args);
+
+    let bins: Vec<_> = puzzles.iter().map(Puzzle::bin_name).collect();
+    let release_dir = build(&bins, &cargo_args)?;
+
+    let mut outcomes = Vec::new();
+    for (p, bin) in puzzles.iter().zip(&bins) {
+        print(
+            if bench { "Benching" } else { "Running" },
+            format!("`{bin}`"),
+        );
+        let mut cmd = process::Command::new(release_dir.join(bin));
+        cmd.envs(config::get().input_env());
+        // The JSON output must come last to override any configured output
+        cmd.args(&bin_args).args(["--output", "json"]);
+        if bench {
+            cmd.arg("--bench");
+        }
+        let output = cmd.stderr(process::Stdio::null()).output()?;
+        let outcome = match serde_json::from_slice(&output.stdout) {
+            Ok(summary) if output.status.success() => Outcome::Summary(summary),
+            _ => Outcome::Failed,
+        };
+        outcomes.push((*p, outcome));
+    }
+    Ok(outcomes)
+}
+
+/// Builds the binaries with JSON output, returning the directory they are in.
+pub fn build(bins: &[String], cargo_args: &[String]) -> Result<PathBuf> {
+    cargo(
+        ["build", "--release", "--quiet", "--features", "json"],
+        bins,
+        cargo_args,
+    )?;
+    Ok(release_dir())
+}
+
+/// Returns the directory that release binaries are built in.
+pub fn release_dir() -> PathBuf {
+    let target_dir = env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| config::workspace_dir().join("target"));
+    target_dir.join("release")
+}
+
+fn cargo<const N: usize>(cmd: [&str; N], bins: &[String], args: &[String]) -> Result<()> {
+    let status = cargo_command()
+        .args(cmd)
+        .args(bins.iter().flat_map(|b| ["--bin", b]))
+        .args(args)
+        .status()?;
+    if !status.success() {
+        bail!("failed to build binaries");
+    }
+    Ok(())
+}
+
+/// Prints the table and exits with a failure code if anything failed.
+fn finish(table: &str, outcomes: &[(Puzzle, Outcome)]) -> Result<()> {
+    println!();
+    print!("{table}");
+    let failures = outcomes.iter().filter(|(_, o)| o.is_failure()).count();
+    if failures > 0 {
+        warning(
+            "Failed",
+            format!("{failures} of {} puzzles", outcomes.len()),
+        );
+        process::exit(1);
+    }
+    Ok(())
+}
+
+/// Parses the `test result` line printed by the test harness.
+fn parse_test_result(stdout: &str) -> Option<(usize, usize)> {
+    let line = stdout.lines().find(|l| l.starts_with("test result:"))?;
+    let count = |suffix: &str| -> Option<usize> {
+        let i = line.find(suffix)?;
+        line[..i].rsplit(' ').next()?.parse().ok()
+    };
+    Some((count(" passed")?, count(" failed")?))
+}
+
+impl Outcome {
+    fn is_failure(&self) -> bool {
+        match self {
+            Self::Summary(Summary::Run(runs)) => runs
+                .iter()
+                .any(|r| r.result == FAILED || r.is_correct() == Some(false)),
+            Self::Summary(Summary::Bench(_)) => false,
+            Self::Test { failed, .. } => *failed > 0,
+            Self::Failed => true,
+        }
+    }
+}
+
+impl Bench {
+    /// Returns whether this is a part rather than the parse or shared solve
+    /// step.
+    pub fn is_part(&self) -> bool {
+        self.kind == Kind::Part
+    }
+}
+
+impl Run {
+    /// Returns whether this is a part rather than the shared solve step.
+    pub fn is_part(&self) -> bool {
+        self.kind == Kind::Part
+    }
+
+    pub fn is_correct(&self) -> Option<bool> {
+        self.expected.as_ref().map(|e| *e == self.result)
+    }
+}
+
+fn render_runs(outcomes: &[(Puzzle, Outcome)], style: Style) -> String {
+    let header = ["Year", "Day", "Part 1", "Part 2", "Time"];
+    render(&header, outcomes, style, |outcomes| {
+        let mut stars = 0;
+        let mut total = 0.0;
+        let mut rows = Vec::new();
+        for (p, outcome) in outcomes {
+            let mut row = vec![Cell::Plain(p.year.to_string()), day_cell(p)];
+            match outcome {
+                Outcome::Summary(Summary::Run(runs)) => {
+                    let parts: Vec<_> = runs.iter().filter(|r| r.is_part()).collect();
+                    for i in 0..2 {
+                        row.push(match parts.get(i) {
+                            Some(run) => answer_cell(run),
+                            None => Cell::Dim("-".into()),
+                        });
+                    }
+                    let elapsed: f64 = runs.iter().map(|r| r.elapsed).sum();
+                    stars += runs.iter().filter(|r| r.is_correct() == Some(true)).count();
+                    total += elapsed;
+                    row.push(Cell::Dim(human_time(elapsed)));
+                }
+                _ => row.extend(failed_row(3)),
+            }
+            rows.push(row);
+        }
+        let total = vec![
+            Cell::Good(format!("{stars} ⭐")),
+            Cell::Plain(String::new()),
+            Cell::Plain(human_time(total)),
+        ];
+        (rows, total)
+    })
+}
+
+fn render_benches(outcomes: &[(Puzzle, Outcome)], style: Style) -> String {
+    let header = ["Year", "Day", "Part 1", "Part 2", "Total"];
+    render(&header, outcomes, style, |outcomes| {
+        let mut total = 0.0;
+        let mut rows = Vec::new();
+        for (p, outcome) in outcomes {
+            let mut row = vec![Cell::Plain(p.year.to_string()), day_cell(p)];
+            match outcome {
+                Outcome::Summary(Summary::Bench(benches)) => {
+                    let parts: Vec<_> = benches.iter().filter(|b| b.is_part()).collect();
+                    for i in 0..2 {
+                        row.push(match parts.get(i) {
+                            Some(b) => Cell::Plain(human_time(b.stats.mean)),
+                            None => Cell::Dim("-".into()),
+                        });
+                    }
+                    let sum: f64 = benches.iter().map(|b| b.stats.mean).sum();
+                    total += sum;
+                    row.push(Cell::Plain(human_time(sum)));
+                }
+                _ => row.extend(failed_row(3)),
+            }
+            rows.push(row);
+        }
+        let total = vec![
+            Cell::Plain(String::new()),
+            Cell::Plain(String::new()),
+            Cell::Plain(human_time(total)),
+        ];
+        (rows, total)
+    })
+}
+
+fn render_tests(outcomes: &[(Puzzle, Outcome)], style: Style) -> String {
+    let header = ["Year", "Day", "Passed", "Failed"];
+    render(&header, outcomes, style, |outcomes| {
+        let mut passed_total = 0;
+        let mut failed_total = 0;
+        let mut rows = Vec::new();
+        for (p, outcome) in outcomes {
+            let mut row = vec![Cell::Plain(p.year.to_string()), day_cell(p)];
+            match outcome {
+                Outcome::Test { passed, failed } => {
+                    passed_total += passed;
+                    failed_total += failed;
+                    row.push(Cell::Good(passed.to_string()));
+                    row.push(count_cell(*failed));
+                }
+                _ => row.extend(failed_row(2)),
+            }
+            rows.push(row);
+        }
+        let total = vec![
+            Cell::Good(passed_total.to_string()),
+            count_cell(failed_total),
+        ];
+        (rows, total)
+    })
+}
+
+pub fn day_cell(p: &Puzzle) -> Cell {
+    Cell::Plain(format!("{:02}", p.day))
+}
+
+fn count_cell(failed: usize) -> Cell {
+    match failed {
+        0 => Cell::Dim("0".into()),
+        n => Cell::Bad(n.to_string()),
+    }
+}
+
+pub fn failed_row(n: usize) -> Vec<Cell> {
+    let mut row = vec![Cell::Bad(FAILED.into())];
+    row.resize(n, Cell::Plain(String::new()));
+    row
+}
+
+pub fn answer_cell(run: &Run) -> Cell {
+    let result = match run.result.lines().count() {
+        0 | 1 => run.result.clone(),
+        n => format!("<{n} lines>"),
+    };
+    let result = match result.chars().count() {
+        n if n > 20 => result.chars().take(19).chain(['…']).collect(),
+        _ => result,
+    };
+    match run.is_correct() {
+        _ if run.result == FAILED => Cell::Bad(result),
+        Some(true) => Cell::Good(format!("{result} ✓")),
+        Some(false) => Cell::Bad(format!("{result} ✗")),
+        None => Cell::Plain(result),
+    }
+}
+
+/// Renders a table grouped by year with a total row after each year.
+///
+/// The function returns the rows for a single year and the cells of the total
+/// row after the year and day columns.
+fn render<F>(header: &[&str], outcomes: &[(Puzzle, Outcome)], style: Style, f: F) -> String
+where
+    F: Fn(&[(Puzzle, Outcome)]) -> (Vec<Vec<Cell>>, Vec<Cell>),
+{
+    let mut groups: Vec<(Vec<Vec<Cell>>, Vec<Cell>)> = Vec::new();
+    let mut rest = outcomes;
+    while let Some((p, _)) = rest.first() {
+        let n = rest.iter().take_while(|(q, _)| q.year == p.year).count();
+        let (chunk, tail) = rest.split_at(n);
+        let (rows, total) = f(chunk);
+        let mut row = vec![Cell::Plain(p.year.to_string()), Cell::Plain("Total".into())];
+        row.extend(total);
+        groups.push((rows, row));
+        rest = tail;
+    }
+
+    let mut table = Table::new(header);
+    for (rows, total) in groups {
+        for row in rows {
+            table.row(row);
+        }
+        table.bold_row(total);
+    }
+    table.render(style)
+}
+
+/// Formats a number of seconds for humans.
+pub fn human_time(secs: f64) -> String {
+    let (v, unit) = match secs {
+        s if s >= 1.0 => (s, "s"),
+        s if s >= 1e-3 => (s * 1e3, "ms"),
+        s if s >= 1e-6 => (s * 1e6, "µs"),
+        s => (s * 1e9, "ns"),
+    };
+    format!("{v:.2} {unit}")
+}
+
+/// Parses the puzzle from a binary name like `202301`.
+pub fn parse_bin_name(name: &str) -> Option<Puzzle> {
+    if name.len() != 6 {
+        return None;
+    }
+    let year = name.get(..4)?.parse().ok()?;
+    let day = name.get(4..)?.parse().ok()?;
+    Some(Puzzle { year, day })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn puzzle(year: u32, day: u32) -> Puzzle {
+        Puzzle { year, day }
+    }
+
+    fn run(result: &str, elapsed: f64, expected: Option<&str>) -> Run {
+        Run {
+            name: String::new(),
+            kind: Kind::Part,
+            result: result.into(),
+            elapsed,
+            expected: expected.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn summary_deserialize() {
+        let summary: Summary = serde_json::from_str(
+            r#"{"runs":[{"name":"Part 1","result":"1337","elapsed":0.001,"expected":"1337"}]}"#,
+        )
+        .unwrap();
+        let Summary::Run(runs) = summary else {
+            panic!("expected runs");
+        };
+        assert_eq!(runs[0].is_correct(), Some(true));
+        assert!(runs[0].is_part());
+
+        // A part is only the shared step if it is marked as one
+        let summary: Summary = serde_json::from_str(
+            r#"{"runs":[{"name":"Solve","kind":"solve","result":"","elapsed":0.001},{"name":"Solve","kind":"part","result":"7","elapsed":0.001}]}"#,
+        )
+        .unwrap();
+        let Summary::Run(runs) = summary else {
+            panic!("expected runs");
+        };
+        assert!(!runs[0].is_part());
+        assert!(runs[1].is_part());
+
+        let summary: Summary = serde_json::from_str(
+            r#"{"benches":[{"name":"Parse","samples":3,"min":1.0,"max":3.0,"mean":2.0,"std_dev":0.5}]}"#,
+        )
+        .unwrap();
+        assert!(matches!(summary, Summary::Bench(b) if b[0].stats.mean == 2.0));
+    }
+
+    #[test]
+    fn render_runs_table() {
+        let outcomes = vec![
+            (
+                puzzle(2021, 1),
+                Outcome::Summary(Summary::Run(vec![
+                    Run {
+                        name: "Solve".into(),
+                        kind: Kind::Solve,
+                        ..run("", 0.000_1, None)
+                    },
+                    run("1559", 0.000_1, Some("1559")),
+                    run("1600", 0.000_2, Some("1600")),
+                ])),
+            ),
+            (
+                puzzle(2021, 2),
+                Outcome::Summary(Summary::Run(vec![
+                    run("12", 0.001, Some("13")),
+                    run("a\nb", 0.002, None),
+                ])),
+            ),
+            (puzzle(2022, 1), Outcome::Failed),
+        ];
+        assert_eq!(
+            render_runs(&outcomes, Style::Plain),
+            "\
+Year  Day    Part 1  Part 2     Time
+2021  01     1559 ✓  1600 ✓     400.00 µs
+2021  02     12 ✗    <2 lines>  3.00 ms
+2021  Total  2 ⭐               3.40 ms
+2022  01     🚨👻🚨
+2022  Total  0 ⭐               0.00 ns
+"
+        );
+    }
+
+    #[test]
+    fn render_benches_table() {
+        let bench = |name: &str, kind, mean| Bench {
+            name: name.into(),
+            kind,
+            stats: Stats {
+                samples: 100,
+                min: mean,
+                max: mean,
+                mean,
+                std_dev: 0.0,
+            },
+        };
+        let outcomes = vec![(
+            puzzle(2023, 5),
+            Outcome::Summary(Summary::Bench(vec![
+                bench("Parse", Kind::Parse, 0.000_01),
+                bench("Part 1", Kind::Part, 0.000_02),
+                bench("Part 2", Kind::Part, 1.5),
+            ])),
+        )];
+        assert_eq!(
+            render_benches(&outcomes, Style::Plain),
+            "\
+Year  Day    Part 1    Part 2  Total
+2023  05     20.00 µs  1.50 s  1.50 s
+2023  Total                    1.50 s
+"
+        );
+    }
+
+    #[test]
+    fn render_tests_table() {
+        let outcomes = vec![
+            (
+                puzzle(2020, 1),
+                Outcome::Test {
+                    passed: 2,
+                    failed: 0,
+                },
+            ),
+            (
+                puzzle(2020, 2),
+                Outcome::Test {
+                    passed: 1,
+                    failed: 1,
+                },
+            ),
+        ];
+        assert_eq!(
+            render_tests(&outcomes, Style::Plain),
+            "\
+Year  Day    Passed  Failed
+2020  01     2       0
+2020  02     1       1
+2020  Total  3       1
+"
+        );
+    }
+
+    #[test]
+    fn parse_test_result_line() {
+        let stdout = "\nrunning 2 tests\n..\ntest result: ok. 2 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s\n";
+        assert_eq!(parse_test_result(stdout), Some((2, 0)));
+        assert_eq!(parse_test_result("error"), None);
+    }
+
+    #[test]
+    fn bin_name() {
+        assert_eq!(parse_bin_name("202301"), Some(puzzle(2023, 1)));
+        assert_eq!(puzzle(2023, 1).bin_name(), "202301");
+        assert_eq!(parse_bin_name("2019-bench-intcode"), None);
+    }
+}
@@ -0,0 +1,113 @@
+//! The Advent of Code calendar.
+
+use anyhow::{ensure, Result};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+use crate::batch::Puzzle;
+
+/// The first year of Advent of Code.
+pub const FIRST_YEAR: u32 = 2015;
+
+/// Returns the number of puzzles in the given year.
+pub fn last_day(year: u32) -> u32 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// Returns the time the puzzle is released, midnight UTC-5.
+pub fn release_time(year: u32, day: u32) -> Result<OffsetDateTime> {
+    ensure!(
+        (1..=last_day(year)).contains(&day),
+        "there is no puzzle (year: {year:04}, day: {day:02})"
+    );
+    let date = Date::from_calendar_date(year.try_into()?, Month::December, day.try_into()?)?;
+    Ok(PrimitiveDateTime::new(date, Time::from_hms(5, 0, 0)?).assume_utc())
+}
+
+/// Returns whether the puzzle has been released.
+pub fn is_released(year: u32, day: u32) -> Result<bool> {
+    Ok(OffsetDateTime::now_utc() >= release_time(year, day)?)
+}
+
+/// Returns the latest puzzle released at the given time.
+pub fn latest(now: OffsetDateTime) -> Puzzle {
+    // Puzzles are released at midnight UTC-5, shifting by five hours means
+    // the date is the same as the date of the latest puzzle.
+    let date = (now - time::Duration::hours(5)).date();
+    let year = date.year() as u32;
+    if date.month() == Month::December {
+        Puzzle {
+            year,
+            day: (date.day() as u32).min(last_day(year)),
+        }
+    } else {
+        Puzzle {
+            year: year - 1,
+            day: last_day(year - 1),
+        }
+    }
+}
+
+/// Returns the next puzzle to be released after the given time.
+pub fn next(now: OffsetDateTime) -> Puzzle {
+    let Puzzle { year, day } = latest(now);
+    if day < last_day(year) {
+        Puzzle { year, day: day + 1 }
+    } else {
+        Puzzle {
+            year: year + 1,
+            day: 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use time::macros::datetime;
+
+    #[test]
+    fn latest_puzzle() {
+        let latest = |now| {
+            let Puzzle { year, day } = latest(now);
+            (year, day)
+        };
+        assert_eq!(latest(datetime!(2023-12-17 04:59 UTC)), (2023, 16));
+        assert_eq!(latest(datetime!(2023-12-17 05:00 UTC)), (2023, 17));
+        assert_eq!(latest(datetime!(2023-12-01 04:59 UTC)), (2022, 25));
+        assert_eq!(latest(datetime!(2023-12-31 12:00 UTC)), (2023, 25));
+        assert_eq!(latest(datetime!(2024-01-01 04:00 UTC)), (2023, 25));
+        assert_eq!(latest(datetime!(2024-07-01 00:00 UTC)), (2023, 25));
+        assert_eq!(latest(datetime!(2025-12-20 12:00 UTC)), (2025, 12));
+        assert_eq!(latest(datetime!(2026-10-19 12:00 UTC)), (2025, 12));
+    }
+
+    #[test]
+    fn next_puzzle() {
+        let next = |now| {
+            let Puzzle { year, day } = next(now);
+            (year, day)
+        };
+        assert_eq!(next(datetime!(2023-12-17 04:59 UTC)), (2023, 17));
+        assert_eq!(next(datetime!(2023-12-17 05:00 UTC)), (2023, 18));
+        assert_eq!(next(datetime!(2023-12-01 04:59 UTC)), (2023, 1));
+        assert_eq!(next(datetime!(2023-12-25 05:00 UTC)), (2024, 1));
+        assert_eq!(next(datetime!(2025-12-11 12:00 UTC)), (2025, 12));
+        assert_eq!(next(datetime!(2026-10-19 12:00 UTC)), (2026, 1));
+    }
+
+    #[test]
+    fn release() {
+        assert_eq!(
+            release_time(2023, 1).unwrap(),
+            datetime!(2023-12-01 05:00 UTC)
+        );
+        assert!(release_time(2023, 26).is_err());
+        assert!(release_time(2025, 13).is_err());
+        assert!(release_time(2023, 256 + 1).is_err());
+    }
+}
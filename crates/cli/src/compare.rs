@@ -0,0 +1,337 @@
+//! Benchmark a solution at two git revisions side by side.
+//!
+//! Each revision is checked out in a worktree under `target/advent/compare`
+//! and built with its own target directory, which is kept so that later
+//! comparisons only rebuild what changed. The benchmarks are then run in
+//! alternating rounds so that any drift in the machine, e.g. thermal
+//! throttling, affects both revisions equally.
+
+use std::env;
+use std::fs;
+use std::os::unix;
+use std::path::PathBuf;
+use std::process;
+
+use anyhow::{bail, Result};
+use argh::FromArgs;
+
+use crate::batch::{human_time, Bench, Summary};
+use crate::git::{self, Worktree};
+use crate::table::{Cell, Style, Table};
+use crate::{check_input, config, parse_args, print};
+
+/// Two-sided critical values of Student's t-distribution at the 5% level for
+/// 1 to 30 degrees of freedom.
+const T_CRITICAL: [f64; 30] = [
+    12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+    2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+    2.052, 2.048, 2.045, 2.042,
+];
+
+/// Compare the benchmark times of the solution at two git revisions.
+#[derive(Debug, FromArgs)]
+struct Opt {
+    /// the baseline revision, e.g. a branch, tag or commit
+    #[argh(positional)]
+    rev_a: String,
+    /// the revision to compare against the baseline, defaults to HEAD
+    #[argh(positional, default = "String::from(\"HEAD\")")]
+    rev_b: String,
+    /// the number of times to benchmark each revision
+    #[argh(option, default = "5")]
+    rounds: usize,
+}
+
+/// A revision checked out and built for comparison.
+#[derive(Debug)]
+struct Revision {
+    label: String,
+    binary: PathBuf,
+    _worktree: Worktree,
+}
+
+/// The mean time of each round for a single benchmark.
+#[derive(Debug, Clone, PartialEq)]
+struct Samples {
+    name: String,
+    means: Vec<f64>,
+}
+
+pub fn compare(year: u32, day: u32, args: &[String]) -> Result<()> {
+    let Opt {
+        rev_a,
+        rev_b,
+        rounds,
+    } = parse_args("compare", args);
+    if rounds < 2 {
+        bail!("at least 2 rounds are required to test significance");
+    }
+    check_input(year, day)?;
+
+    let bin = format!("{year:04}{day:02}");
+    let a = checkout(&rev_a, &bin)?;
+    let b = checkout(&rev_b, &bin)?;
+
+    let mut samples_a = Vec::new();
+    let mut samples_b = Vec::new();
+    for round in 0..rounds {
+        print(
+            "Benching",
+            format!("`{bin}` round {} of {rounds}", round + 1),
+        );
+        // Alternate which revision goes first so neither is always benched
+        // on a warmer or cooler machine
+        let (first, second) = match round % 2 {
+            0 => ((&a, &mut samples_a), (&b, &mut samples_b)),
+            _ => ((&b, &mut samples_b), (&a, &mut samples_a)),
+        };
+        for (rev, samples) in [first, second] {
+            add_round(samples, &bench(rev)?);
+        }
+    }
+
+    println!();
+    print!(
+        "{}",
+        render(&a.label, &b.label, &samples_a, &samples_b, Style::stdout())
+    );
+    Ok(())
+}
+
+/// Checks out and builds the revision.
+fn checkout(rev: &str, bin: &str) -> Result<Revision> {
+    let commit = git::resolve(rev)?;
+    let short = &commit[..7];
+    let dir = config::workspace_dir()
+        .join("target")
+        .join("advent")
+        .join("compare")
+        .join(&commit);
+    let worktree = Worktree::add(&dir.join("src"), &commit)?;
+
+    // Older revisions might not read the configured input directory, so the
+    // inputs are linked to where they used to be
+    let input = worktree.path.join("input");
+    if !input.exists() {
+        unix::fs::symlink(config::get().input_dir(), &input)?;
+    }
+
+    // The lock file is not necessarily committed, so the workspace's is used
+    // to build both revisions with the same dependency versions
+    let lock = worktree.path.join("Cargo.lock");
+    let workspace_lock = config::workspace_dir().join("Cargo.lock");
+    if !lock.exists() && workspace_lock.exists() {
+        fs::copy(workspace_lock, lock)?;
+    }
+
+    print("Building", format!("`{bin}` at `{rev}` ({short})"));
+    let target_dir = dir.join("target");
+    let status = process::Command::new(env!("CARGO"))
+        .args(["build", "--release", "--quiet", "--features", "json"])
+        .args(["--bin", bin])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .current_dir(&worktree.path)
+        // Otherwise the workspace directory would be inherited from this
+        // workspace instead of being set to the worktree
+        .env_remove("CARGO_WORKSPACE_DIR")
+        .status()?;
+    if !status.success() {
+        bail!("failed to build `{bin}` at `{rev}` ({short})");
+    }
+
+    Ok(Revision {
+        label: format!("{rev} ({short})"),
+        binary: target_dir.join("release").join(bin),
+        _worktree: worktree,
+    })
+}
+
+/// Benchmarks the revision once.
+fn bench(rev: &Revision) -> Result<Vec<Bench>> {
+    let output = process::Command::new(&rev.binary)
+        .envs(config::get().input_env())
+        .args(["--bench", "--output", "json"])
+        .env_remove("ADVENT_SUMMARY")
+        .stderr(process::Stdio::null())
+        .output()?;
+    match serde_json::from_slice(&output.stdout) {
+        Ok(Summary::Bench(benches)) if output.status.success() => Ok(benches),
+        _ => bail!("failed to benchmark `{}`", rev.label),
+    }
+}
+
+/// Adds the mean of each benchmark in the round to the samples.
+fn add_round(samples: &mut Vec<Samples>, benches: &[Bench]) {
+    for bench in benches {
+        match samples.iter_mut().find(|s| s.name == bench.name) {
+            Some(s) => s.means.push(bench.stats.mean),
+            None => samples.push(Samples {
+                name: bench.name.clone(),
+                means: vec![bench.stats.mean],
+            }),
+        }
+    }
+}
+
+fn render(
+    label_a: &str,
+    label_b: &str,
+    samples_a: &[Samples],
+    samples_b: &[Samples],
+    style: Style,
+) -> String {
+    let mut table = Table::new(&["Part", label_a, label_b, "Speedup", "Significant"]);
+    for a in samples_a {
+        let Some(b) = samples_b.iter().find(|b| b.name == a.name) else {
+            table.row(vec![
+                Cell::Plain(a.name.clone()),
+                time(&a.means),
+                Cell::Dim("-".into()),
+                Cell::Dim("-".into()),
+                Cell::Dim("-".into()),
+            ]);
+            continue;
+        };
+        let speedup = mean(&a.means) / mean(&b.means);
+        let speedup_cell = format!("{speedup:.2}×");
+        let (speedup_cell, significant_cell) = match significant(&a.means, &b.means) {
+            true if speedup >= 1.0 => (Cell::Good(speedup_cell), Cell::Plain("yes".into())),
+            true => (Cell::Bad(speedup_cell), Cell::Plain("yes".into())),
+            false => (Cell::Plain(speedup_cell), Cell::Dim("no".into())),
+        };
+        table.row(vec![
+            Cell::Plain(a.name.clone()),
+            time(&a.means),
+            time(&b.means),
+            speedup_cell,
+            significant_cell,
+        ]);
+    }
+    for b in samples_b {
+        if !samples_a.iter().any(|a| a.name == b.name) {
+            table.row(vec![
+                Cell::Plain(b.name.clone()),
+                Cell::Dim("-".into()),
+                time(&b.means),
+                Cell::Dim("-".into()),
+                Cell::Dim("-".into()),
+            ]);
+        }
+    }
+    table.render(style)
+}
+
+/// Formats the mean time with the relative standard deviation of the rounds.
+fn time(means: &[f64]) -> Cell {
+    let m = mean(means);
+    let rsd = variance(means).sqrt() / m * 100.0;
+    Cell::Plain(format!("{} ± {rsd:.1}%", human_time(m)))
+}
+
+/// Returns whether the means of the samples differ at the 5% significance
+/// level using Welch's t-test.
+fn significant(a: &[f64], b: &[f64]) -> bool {
+    let (na, nb) = (a.len() as f64, b.len() as f64);
+    let (sa, sb) = (variance(a) / na, variance(b) / nb);
+    let diff = (mean(a) - mean(b)).abs();
+    if sa + sb == 0.0 {
+        return diff > 0.0;
+    }
+    let t = diff / (sa + sb).sqrt();
+    let df = (sa + sb).powi(2) / (sa.powi(2) / (na - 1.0) + sb.powi(2) / (nb - 1.0));
+    let critical = match df.floor() as usize {
+        0 => T_CRITICAL[0],
+        df if df <= T_CRITICAL.len() => T_CRITICAL[df - 1],
+        _ => 1.96,
+    };
+    t > critical
+}
+
+fn mean(xs: &[f64]) -> f64 {
+    xs.iter().sum::<f64>() / xs.len() as f64
+}
+
+/// Returns the sample variance.
+fn variance(xs: &[f64]) -> f64 {
+    if xs.len() < 2 {
+        return 0.0;
+    }
+    let m = mean(xs);
+    xs.iter().map(|x| (x - m).powi(2)).sum::<f64>() / (xs.len() - 1) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples(name: &str, means: &[f64]) -> Samples {
+        Samples {
+            name: name.into(),
+            means: means.to_vec(),
+        }
+    }
+
+    #[test]
+    fn significant_welch() {
+        let a = [1.00, 1.02, 0.98, 1.01, 0.99];
+        let b = [0.80, 0.82, 0.79, 0.81, 0.78];
+        assert!(significant(&a, &b));
+        assert!(significant(&b, &a));
+        // Overlapping noisy samples are not significant
+        let c = [1.10, 0.90, 1.05, 0.95, 1.00];
+        assert!(!significant(&a, &c));
+        // Identical rounds are only significant if the means differ
+        assert!(!significant(&[1.0, 1.0], &[1.0, 1.0]));
+        assert!(significant(&[1.0, 1.0], &[2.0, 2.0]));
+    }
+
+    #[test]
+    fn add_round_groups_by_name() {
+        let bench = |name: &str, mean| Bench {
+            name: name.into(),
+            kind: crate::batch::Kind::Part,
+            stats: crate::batch::Stats {
+                samples: 25,
+                min: mean,
+                max: mean,
+                mean,
+                std_dev: 0.0,
+            },
+        };
+        let mut s = Vec::new();
+        add_round(&mut s, &[bench("Part 1", 1.0), bench("Part 2", 2.0)]);
+        add_round(&mut s, &[bench("Part 1", 1.5), bench("Part 2", 2.5)]);
+        assert_eq!(
+            s,
+            [
+                samples("Part 1", &[1.0, 1.5]),
+                samples("Part 2", &[2.0, 2.5])
+            ]
+        );
+    }
+
+    #[test]
+    fn render_speedup() {
+        let a = [
+            samples("Part 1", &[2e-3, 2.02e-3, 1.98e-3]),
+            samples("Part 2", &[1.5e-3, 1.0e-3, 2.0e-3]),
+            samples("Part 3", &[1e-6, 1e-6, 1e-6]),
+        ];
+        let b = [
+            samples("Part 1", &[1e-3, 1.01e-3, 0.99e-3]),
+            samples("Part 2", &[1.5e-3, 1.25e-3, 1.75e-3]),
+            samples("Solve", &[5e-6, 5e-6, 5e-6]),
+        ];
+        assert_eq!(
+            render("main (1234567)", "HEAD (89abcde)", &a, &b, Style::Plain),
+            "\
+Part    main (1234567)   HEAD (89abcde)   Speedup  Significant
+Part 1  2.00 ms ± 1.0%   1.00 ms ± 1.0%   2.00×    yes
+Part 2  1.50 ms ± 33.3%  1.50 ms ± 16.7%  1.00×    no
+Part 3  1.00 µs ± 0.0%   -                -        -
+Solve   -                5.00 µs ± 0.0%   -        -
+"
+        );
+    }
+}
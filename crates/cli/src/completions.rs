@@ -0,0 +1,452 @@
+//! Shell completions for `cargo advent`.
+//!
+//! The scripts wrap the completions for `cargo` and call back into the CLI with
+//! the words typed so far using the hidden `--complete` option. This way the
+//! years and days can be completed from the `[[bin]]` entries in the manifest
+//! and the parts from the solution binaries themselves.
+
+use std::process;
+
+use anyhow::Result;
+use argh::FromArgs;
+use time::OffsetDateTime;
+
+use crate::batch::{self, Puzzle};
+use crate::select::Select;
+use crate::{calendar, config, parse_args, puzzles, template};
+
+/// Print the shell completion script, see the README for how to install it.
+#[derive(Debug, FromArgs)]
+struct Opt {
+    /// the shell: bash, zsh or fish
+    #[argh(positional)]
+    shell: Shell,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+/// A subcommand and the options it accepts.
+struct Command {
+    name: &'static str,
+    about: &'static str,
+    /// The options that take a value.
+    options: &'static [&'static str],
+    switches: &'static [&'static str],
+    /// The values for the first positional argument.
+    values: &'static [&'static str],
+}
+
+/// The state of the workspace used to complete the years, days and templates.
+#[derive(Debug)]
+struct Workspace {
+    puzzles: Vec<Puzzle>,
+    templates: Vec<String>,
+    current: Puzzle,
+}
+
+/// A completion candidate and its description.
+#[derive(Debug, PartialEq, Eq)]
+struct Candidate {
+    value: String,
+    about: String,
+}
+
+const OPTIONS: &[(&str, &str)] = &[
+    ("--year", "the puzzle year"),
+    ("--day", "the puzzle day"),
+    ("--help", "display usage information"),
+];
+
+const COMMANDS: &[Command] = &[
+    command("bench", "benchmark the solution", &[], &[]),
+    command("build", "build the solution", &[], &[]),
+    command("check", "check the solution", &[], &[]),
+    command("clippy", "lint the solution", &[], &[]),
+    command(
+        "compare",
+        "benchmark the solution at two revisions",
+        &["--rounds"],
+        &[],
+    ),
+    Command {
+        values: &["bash", "zsh", "fish"],
+        ..command("completions", "print the shell completion script", &[], &[])
+    },
+    Command {
+        values: &["show"],
+        ..command("config", "show the configuration", &[], &[])
+    },
+    command(
+        "crosscheck",
+        "run the solution with every profile's input",
+        &[],
+        &[],
+    ),
+    command("doctor", "diagnose problems with the environment", &[], &[]),
+    command(
+        "examples",
+        "download the example inputs",
+        &["--insert"],
+        &[],
+    ),
+    command("history", "show the benchmark history", &["--limit"], &[]),
+    Command {
+        values: &["verify", "refetch"],
+        ..command("inputs", "verify or refetch the puzzle inputs", &[], &[])
+    },
+    command(
+        "leaderboard",
+        "show a private leaderboard",
+        &["--output"],
+        &[],
+    ),
+    command(
+        "new",
+        "add a solution from a template",
+        &["--template"],
+        &[],
+    ),
+    command("open", "open the puzzle in the browser", &[], &[]),
+    command(
+        "readme",
+        "update the progress section of the README",
+        &["--path"],
+        &["--check"],
+    ),
+    command("remove", "remove the solution", &[], &[]),
+    command("rename", "move the solution to another puzzle", &[], &[]),
+    command("run", "run the solution", &[], &[]),
+    command(
+        "status",
+        "show the stars for each puzzle",
+        &["--output"],
+        &["--online"],
+    ),
+    command("submit", "submit the answer for a part", &[], &[]),
+    command("test", "test the solution", &[], &[]),
+    command(
+        "wait",
+        "wait for the puzzle to be released and add a solution",
+        &["--template", "--delay"],
+        &["--no-examples", "--open"],
+    ),
+    Command {
+        values: &["test", "run", "bench"],
+        ..command("watch", "re-run the solution when it changes", &[], &[])
+    },
+];
+
+const fn command(
+    name: &'static str,
+    about: &'static str,
+    options: &'static [&'static str],
+    switches: &'static [&'static str],
+) -> Command {
+    Command {
+        name,
+        about,
+        options,
+        switches,
+        values: &[],
+    }
+}
+
+pub fn completions(args: &[String]) -> Result<()> {
+    if let Some(words) = args.strip_prefix(&[String::from("--complete")]) {
+        let now = OffsetDateTime::now_utc();
+        let workspace = Workspace {
+            // Completions should never fail, so use what can be found
+            puzzles: puzzles().unwrap_or_default(),
+            templates: template::names(&config::workspace_dir()),
+            current: config::get()
+                .current()
+                .unwrap_or_else(|| calendar::latest(now)),
+        };
+        for c in complete(words, &workspace, part_names) {
+            match c.about.is_empty() {
+                true => println!("{}", c.value),
+                false => println!("{}\t{}", c.value, c.about),
+            }
+        }
+        return Ok(());
+    }
+
+    let Opt { shell } = parse_args("completions", args);
+    print!(
+        "{}",
+        match shell {
+            Shell::Bash => include_str!("../completions/advent.bash"),
+            Shell::Zsh => include_str!("../completions/advent.zsh"),
+            Shell::Fish => include_str!("../completions/advent.fish"),
+        }
+    );
+    Ok(())
+}
+
+/// Returns the candidates for the last word, given the words after `advent`.
+fn complete(
+    words: &[String],
+    workspace: &Workspace,
+    parts: impl Fn(Puzzle) -> Vec<String>,
+) -> Vec<Candidate> {
+    let Some((current, words)) = words.split_last() else {
+        return Vec::new();
+    };
+    let previous = words.last().map(String::as_str);
+
+    // Find the selected year and day and the subcommand
+    let (mut year, mut day) = (None, None);
+    let mut command = None;
+    let mut positionals = 0;
+    let mut iter = words.iter().map(String::as_str);
+    while let Some(word) = iter.next() {
+        match (command, word) {
+            (None, "-y" | "--year") => year = iter.next(),
+            (None, "-d" | "--day") => day = iter.next(),
+            (None, w) if w.starts_with('-') => {}
+            (None, w) => command = Some(w),
+            (Some(cmd), w) => {
+                if takes_value(cmd, w) {
+                    iter.next();
+                } else if !w.starts_with('-') {
+                    positionals += 1;
+                }
+            }
+        }
+    }
+
+    let select = |s: Option<&str>| {
+        s.map(|s| argh::FromArgValue::from_arg_value(s).unwrap_or(Select::All))
+            .filter(|s: &Select| !matches!(s, Select::Relative(_)))
+            .unwrap_or(Select::All)
+    };
+    let candidates = match (command, previous) {
+        (None, Some("-y" | "--year")) => {
+            let mut years: Vec<_> = workspace.puzzles.iter().map(|p| p.year).collect();
+            years.dedup();
+            numbers(years)
+        }
+        (None, Some("-d" | "--day")) => {
+            let year = select(year);
+            let mut days: Vec<_> = workspace
+                .puzzles
+                .iter()
+                .filter(|p| year.contains(p.year))
+                .map(|p| p.day)
+                .collect();
+            days.sort();
+            days.dedup();
+            numbers(days)
+        }
+        (None, _) if current.starts_with('-') => OPTIONS
+            .iter()
+            .map(|(value, about)| Candidate::new(value, about))
+            .collect(),
+        (None, _) => COMMANDS
+            .iter()
+            .map(|c| Candidate::new(c.name, c.about))
+            .collect(),
+        (Some(cmd), Some(option)) if takes_value(cmd, option) => match option {
+            "--template" => workspace
+                .templates
+                .iter()
+                .map(|t| Candidate::new(t, ""))
+                .collect(),
+            "--output" if cmd == "status" => values(&["grid", "markdown"]),
+            "--output" => values(&["table", "json", "markdown"]),
+            _ => Vec::new(),
+        },
+        (Some(cmd), _) if current.starts_with('-') => find(cmd)
+            .into_iter()
+            .flat_map(|c| c.options.iter().chain(c.switches))
+            .chain(["--help"].iter())
+            .map(|o| Candidate::new(o, ""))
+            .collect(),
+        (Some("submit"), _) if positionals == 0 => {
+            let puzzle = Puzzle {
+                year: select(year).single().unwrap_or(workspace.current.year),
+                day: select(day).single().unwrap_or(workspace.current.day),
+            };
+            let names = parts(puzzle);
+            (1..=2)
+                .map(|part| {
+                    let about = names.get(part - 1).map_or("", String::as_str);
+                    Candidate::new(&part.to_string(), about)
+                })
+                .collect()
+        }
+        (Some(cmd), _) if positionals == 0 => {
+            find(cmd).map(|c| values(c.values)).unwrap_or_default()
+        }
+        _ => Vec::new(),
+    };
+    candidates
+        .into_iter()
+        .filter(|c| c.value.starts_with(current.as_str()))
+        .collect()
+}
+
+/// Returns whether the option for the subcommand takes a value.
+fn takes_value(command: &str, option: &str) -> bool {
+    find(command).map_or(false, |c| c.options.contains(&option))
+}
+
+fn find(command: &str) -> Option<&'static Command> {
+    COMMANDS.iter().find(|c| c.name == command)
+}
+
+fn numbers(ns: Vec<u32>) -> Vec<Candidate> {
+    ns.into_iter()
+        .map(|n| Candidate::new(&n.to_string(), ""))
+        .chain([Candidate::new("all", "")])
+        .collect()
+}
+
+fn values(values: &[&str]) -> Vec<Candidate> {
+    values.iter().map(|v| Candidate::new(v, "")).collect()
+}
+
+/// Returns the part names from the solution binary, if it has been built.
+///
+/// The binary is never built here since that would make completion slow.
+fn part_names(p: Puzzle) -> Vec<String> {
+    let output = process::Command::new(batch::release_dir().join(p.bin_name()))
+        .arg("--list-parts")
+        .stderr(process::Stdio::null())
+        .output();
+    match output {
+        Ok(output) if output.status.success() => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(String::from)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+impl Candidate {
+    fn new(value: &str, about: &str) -> Self {
+        Self {
+            value: value.to_owned(),
+            about: about.to_owned(),
+        }
+    }
+}
+
+impl argh::FromArgValue for Shell {
+    fn from_arg_value(value: &str) -> Result<Self, String> {
+        match value {
+            "bash" => Ok(Self::Bash),
+            "zsh" => Ok(Self::Zsh),
+            "fish" => Ok(Self::Fish),
+            _ => Err("expected `bash`, `zsh` or `fish`".into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace() -> Workspace {
+        let puzzles = [(2021, 1), (2022, 1), (2022, 2), (2022, 10), (2023, 3)];
+        Workspace {
+            puzzles: puzzles
+                .into_iter()
+                .map(|(year, day)| Puzzle { year, day })
+                .collect(),
+            templates: vec!["default".into(), "grid".into()],
+            current: Puzzle { year: 2023, day: 3 },
+        }
+    }
+
+    fn values(line: &str) -> Vec<String> {
+        let words: Vec<_> = line.split(' ').map(String::from).collect();
+        let parts = |p: Puzzle| match p.bin_name().as_str() {
+            "202303" => vec!["Part 1".into(), "Part 2".into()],
+            _ => Vec::new(),
+        };
+        complete(&words, &workspace(), parts)
+            .into_iter()
+            .map(|c| match c.about.is_empty() {
+                true => c.value,
+                false => format!("{}\t{}", c.value, c.about),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn complete_years_and_days() {
+        assert_eq!(values("-y "), ["2021", "2022", "2023", "all"]);
+        assert_eq!(values("-y 202"), ["2021", "2022", "2023"]);
+        assert_eq!(values("-y 2022 -d "), ["1", "2", "10", "all"]);
+        assert_eq!(values("--year 2022 --day 1"), ["1", "10"]);
+        assert_eq!(values("-d "), ["1", "2", "3", "10", "all"]);
+        assert_eq!(values("-y prev -d a"), ["all"]);
+    }
+
+    #[test]
+    fn commands_match_main() {
+        use crate::Command as Main;
+        use argh::FromArgValue;
+
+        // The commands named in the parse error and the Cargo subcommands
+        let err = Main::from_arg_value("").unwrap_err();
+        let names = err
+            .trim_start_matches("expected one of: ")
+            .trim_end_matches(", or a Cargo subcommand")
+            .split(", ")
+            .chain(["check", "build", "test", "run", "clippy"]);
+        let mut expected: Vec<_> = names
+            .inspect(|name| assert!(Main::from_arg_value(name).is_ok(), "`{name}`"))
+            .collect();
+        expected.sort_unstable();
+
+        let mut actual: Vec<_> = COMMANDS.iter().map(|c| c.name).collect();
+        actual.sort_unstable();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn complete_commands() {
+        assert_eq!(
+            values("--"),
+            [
+                "--year\tthe puzzle year",
+                "--day\tthe puzzle day",
+                "--help\tdisplay usage information"
+            ]
+        );
+        assert_eq!(
+            values("-y 2022 c"),
+            [
+                "check\tcheck the solution",
+                "clippy\tlint the solution",
+                "compare\tbenchmark the solution at two revisions",
+                "completions\tprint the shell completion script",
+                "config\tshow the configuration",
+                "crosscheck\trun the solution with every profile's input",
+            ]
+        );
+        assert_eq!(values("status -"), ["--output", "--online", "--help"]);
+        assert_eq!(values("status --output "), ["grid", "markdown"]);
+        assert_eq!(values("leaderboard 123 --output m"), ["markdown"]);
+        assert_eq!(values("wait --template "), ["default", "grid"]);
+        assert_eq!(values("wait --delay "), Vec::<String>::new());
+        assert_eq!(values("inputs "), ["verify", "refetch"]);
+        assert_eq!(values("inputs verify "), Vec::<String>::new());
+        assert_eq!(values("watch --help b"), ["bench"]);
+        assert_eq!(values("unknown "), Vec::<String>::new());
+    }
+
+    #[test]
+    fn complete_parts() {
+        assert_eq!(values("submit "), ["1\tPart 1", "2\tPart 2"]);
+        assert_eq!(values("-y 2022 -d 1 submit "), ["1", "2"]);
+        assert_eq!(values("submit 1 "), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,692 @@
+//! Layered configuration.
+//!
+//! Values are read from the following places, later ones taking precedence.
+//!
+//! - the defaults
+//! - the user configuration in `~/.config/advent/config.toml`
+//! - the workspace configuration in `.advent.toml`
+//! - the `ADVENT_SESSION`, `ADVENT_BASE_URL` and `ADVENT_PROFILE` environment
+//!   variables
+//!
+//! Named profiles each have their own session and store their inputs in a
+//! subdirectory of the input directory, e.g. `input/<profile>/2023/01.txt`.
+//! The top level session and input directory are used when no profile is
+//! selected.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+use crate::batch::Puzzle;
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// The Advent of Code website.
+const BASE_URL: &str = "https://adventofcode.com";
+
+/// The user agent sent with every request.
+const USER_AGENT: &str = "github.com/rossmacarthur/advent by ross@macarthur.io";
+
+/// Where a configuration value came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Default,
+    File(PathBuf),
+    Env(&'static str),
+}
+
+/// A configuration value and where it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sourced<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+/// The session token, either given directly or read from a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Session {
+    Token(String),
+    File(PathBuf),
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub session: Option<Sourced<Session>>,
+    pub base_url: Sourced<String>,
+    pub browser: Sourced<String>,
+    pub user_agent: Sourced<String>,
+    pub output: Option<Sourced<String>>,
+    pub input_dir: Sourced<PathBuf>,
+    pub input_path: Sourced<String>,
+    pub cargo_args: Sourced<Vec<String>>,
+    pub bin_args: Sourced<Vec<String>>,
+    pub current: Option<Sourced<Current>>,
+    pub profile: Option<Sourced<String>>,
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// A named account with its own session and inputs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    pub session: Option<Sourced<Session>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Current {
+    pub year: u32,
+    pub day: u32,
+}
+
+/// A single configuration file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct Layer {
+    session: Option<String>,
+    session_file: Option<PathBuf>,
+    base_url: Option<String>,
+    browser: Option<String>,
+    user_agent: Option<String>,
+    output: Option<String>,
+    #[serde(default)]
+    input: InputLayer,
+    #[serde(default)]
+    args: ArgsLayer,
+    current: Option<Current>,
+    profile: Option<String>,
+    #[serde(default)]
+    profiles: BTreeMap<String, ProfileLayer>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct ProfileLayer {
+    session: Option<String>,
+    session_file: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct InputLayer {
+    dir: Option<PathBuf>,
+    path: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ArgsLayer {
+    cargo: Option<Vec<String>>,
+    bin: Option<Vec<String>>,
+}
+
+/// Loads the configuration, reporting any errors.
+pub fn init() -> Result<()> {
+    let config = Config::load()?;
+    let _ = CONFIG.set(config);
+    Ok(())
+}
+
+/// Returns the configuration, loading it if [`init`] was not called.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(|| Config::load().expect("failed to load config"))
+}
+
+/// Returns the workspace directory.
+///
+/// Cargo sets `CARGO_WORKSPACE_DIR` when running the CLI using the alias, it
+/// can also be set to use another workspace, e.g. in tests. Otherwise the
+/// workspace the CLI was built in is used.
+pub fn workspace_dir() -> PathBuf {
+    match env::var_os("CARGO_WORKSPACE_DIR") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env!("CARGO_WORKSPACE_DIR")),
+    }
+}
+
+/// Returns the directory for cached responses and request state.
+pub fn cache_dir() -> PathBuf {
+    let dir = match (env::var_os("XDG_CACHE_HOME"), env::var_os("HOME")) {
+        (Some(dir), _) if !dir.is_empty() => PathBuf::from(dir),
+        (_, Some(home)) => PathBuf::from(home).join(".cache"),
+        _ => return workspace_dir().join("target").join("advent"),
+    };
+    dir.join("advent")
+}
+
+/// Returns the path to the user configuration file.
+fn user_path() -> Option<PathBuf> {
+    let dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+    Some(dir.join("advent").join("config.toml"))
+}
+
+impl Config {
+    fn load() -> Result<Self> {
+        let paths = user_path()
+            .into_iter()
+            .chain([workspace_dir().join(".advent.toml")]);
+        let mut layers = Vec::new();
+        for path in paths {
+            match fs::read_to_string(&path) {
+                Ok(s) => {
+                    let layer = toml::from_str(&s)
+                        .with_context(|| format!("failed to parse `{}`", path.display()))?;
+                    layers.push((path, layer));
+                }
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+        let config = Self::from_layers(layers, |k| env::var(k).ok());
+        if let Some(profile) = &config.profile {
+            if !config.profiles.contains_key(&profile.value) {
+                let names: Vec<_> = config.profiles.keys().map(String::as_str).collect();
+                bail!(
+                    "unknown profile `{}` set by {}, expected one of: {}",
+                    profile.value,
+                    profile.source,
+                    names.join(", ")
+                );
+            }
+        }
+        Ok(config)
+    }
+
+    fn from_layers<F>(layers: Vec<(PathBuf, Layer)>, env: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        fn default<T>(value: T) -> Sourced<T> {
+            Sourced::new(value, Source::Default)
+        }
+
+        let mut config = Self {
+            session: None,
+            base_url: default(String::from(BASE_URL)),
+            browser: default(String::from("firefox")),
+            user_agent: default(String::from(USER_AGENT)),
+            output: None,
+            input_dir: default(PathBuf::from("input")),
+            input_path: default(String::from("{year}/{day}.txt")),
+            cargo_args: default(Vec::new()),
+            bin_args: default(Vec::new()),
+            current: None,
+            profile: None,
+            profiles: BTreeMap::new(),
+        };
+
+        for (path, layer) in layers {
+            let source = Source::File(path);
+            if let Some(token) = layer.session {
+                config.session = Some(Sourced::new(Session::Token(token), source.clone()));
+            }
+            if let Some(file) = layer.session_file {
+                config.session = Some(Sourced::new(Session::File(file), source.clone()));
+            }
+            if let Some(url) = layer.base_url {
+                config.base_url = Sourced::new(url, source.clone());
+            }
+            if let Some(browser) = layer.browser {
+                config.browser = Sourced::new(browser, source.clone());
+            }
+            if let Some(user_agent) = layer.user_agent {
+                config.user_agent = Sourced::new(user_agent, source.clone());
+            }
+            if let Some(output) = layer.output {
+                config.output = Some(Sourced::new(output, source.clone()));
+            }
+            if let Some(dir) = layer.input.dir {
+                config.input_dir = Sourced::new(dir, source.clone());
+            }
+            if let Some(path) = layer.input.path {
+                config.input_path = Sourced::new(path, source.clone());
+            }
+            if let Some(args) = layer.args.cargo {
+                config.cargo_args = Sourced::new(args, source.clone());
+            }
+            if let Some(args) = layer.args.bin {
+                config.bin_args = Sourced::new(args, source.clone());
+            }
+            if let Some(current) = layer.current {
+                config.current = Some(Sourced::new(current, source.clone()));
+            }
+            if let Some(profile) = layer.profile {
+                config.profile = Some(Sourced::new(profile, source.clone()));
+            }
+            for (name, layer) in layer.profiles {
+                let profile = config.profiles.entry(name).or_default();
+                if let Some(token) = layer.session {
+                    profile.session = Some(Sourced::new(Session::Token(token), source.clone()));
+                }
+                if let Some(file) = layer.session_file {
+                    profile.session = Some(Sourced::new(Session::File(file), source.clone()));
+                }
+            }
+        }
+
+        if let Some(token) = env("ADVENT_SESSION") {
+            let source = Source::Env("ADVENT_SESSION");
+            config.session = Some(Sourced::new(Session::Token(token), source));
+        }
+        if let Some(url) = env("ADVENT_BASE_URL") {
+            config.base_url = Sourced::new(url, Source::Env("ADVENT_BASE_URL"));
+        }
+        if let Some(profile) = env("ADVENT_PROFILE").filter(|p| !p.is_empty()) {
+            config.profile = Some(Sourced::new(profile, Source::Env("ADVENT_PROFILE")));
+        }
+        let url = config.base_url.value.trim_end_matches('/').len();
+        config.base_url.value.truncate(url);
+
+        config
+    }
+
+    /// Returns the configuration with the given profile selected, or no
+    /// profile for the top level session and input directory.
+    pub fn with_profile(&self, profile: Option<&str>) -> Self {
+        Self {
+            profile: profile.map(|p| Sourced::new(p.to_owned(), Source::Default)),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the session token for the selected profile.
+    pub fn session(&self) -> Result<String> {
+        let session = match &self.profile {
+            Some(profile) => match self.profiles.get(&profile.value) {
+                Some(Profile {
+                    session: Some(session),
+                }) => session,
+                _ => bail!(
+                    "profile `{}` must have a `session` or `session-file` config",
+                    profile.value
+                ),
+            },
+            None => match &self.session {
+                Some(session) => session,
+                None => bail!("`ADVENT_SESSION` or the `session` config must be set"),
+            },
+        };
+        match &session.value {
+            Session::Token(token) => Ok(token.clone()),
+            Session::File(path) => {
+                let path = expand_home(path);
+                let token = fs::read_to_string(&path)
+                    .with_context(|| format!("failed to read session file `{}`", path.display()))?;
+                Ok(token.trim().to_owned())
+            }
+        }
+    }
+
+    /// Returns the absolute path to the input directory for the selected
+    /// profile.
+    pub fn input_dir(&self) -> PathBuf {
+        let dir = workspace_dir().join(expand_home(&self.input_dir.value));
+        match &self.profile {
+            Some(profile) => dir.join(&profile.value),
+            None => dir,
+        }
+    }
+
+    /// Returns the path to the puzzle input relative to the input directory.
+    pub fn input_relative(&self, year: u32, day: u32) -> PathBuf {
+        PathBuf::from(
+            self.input_path
+                .value
+                .replace("{year}", &format!("{year:04}"))
+                .replace("{day}", &format!("{day:02}")),
+        )
+    }
+
+    /// Returns the absolute path to the puzzle input.
+    pub fn input(&self, year: u32, day: u32) -> PathBuf {
+        self.input_dir().join(self.input_relative(year, day))
+    }
+
+    /// Returns the absolute path to a file stored next to the puzzle input,
+    /// e.g. `input/2023/01.example-1.txt` for the extension `example-1.txt`.
+    pub fn input_sibling(&self, year: u32, day: u32, ext: &str) -> PathBuf {
+        self.input(year, day).with_extension(ext)
+    }
+
+    /// Returns the environment variables that tell the solutions where to
+    /// load the inputs from.
+    pub fn input_env(&self) -> [(&'static str, String); 2] {
+        [
+            (
+                "ADVENT_INPUT_DIR",
+                self.input_dir().to_string_lossy().into_owned(),
+            ),
+            ("ADVENT_INPUT_PATH", self.input_path.value.clone()),
+        ]
+    }
+
+    /// Returns the current puzzle, if configured.
+    pub fn current(&self) -> Option<Puzzle> {
+        self.current.as_ref().map(|c| Puzzle {
+            year: c.value.year,
+            day: c.value.day,
+        })
+    }
+
+    /// Renders each value and where it came from.
+    pub fn show(&self) -> String {
+        let mut rows = Vec::new();
+        let mut row = |key: &str, value: String, source: &Source| {
+            rows.push((key.to_owned(), value, source.to_string()));
+        };
+        let (key, value, source) = session_row("", &self.session);
+        row(&key, value, source);
+        row(
+            "base-url",
+            quote(&self.base_url.value),
+            &self.base_url.source,
+        );
+        row("browser", quote(&self.browser.value), &self.browser.source);
+        row(
+            "user-agent",
+            quote(&self.user_agent.value),
+            &self.user_agent.source,
+        );
+        match &self.output {
+            Some(output) => row("output", quote(&output.value), &output.source),
+            None => row("output", String::from("<unset>"), &Source::Default),
+        }
+        row(
+            "input.dir",
+            quote(&self.input_dir.value.display()),
+            &self.input_dir.source,
+        );
+        row(
+            "input.path",
+            quote(&self.input_path.value),
+            &self.input_path.source,
+        );
+        row(
+            "args.cargo",
+            list(&self.cargo_args.value),
+            &self.cargo_args.source,
+        );
+        row(
+            "args.bin",
+            list(&self.bin_args.value),
+            &self.bin_args.source,
+        );
+        match &self.current {
+            Some(Sourced { value, source }) => row(
+                "current",
+                format!("{{ year = {}, day = {} }}", value.year, value.day),
+                source,
+            ),
+            None => row("current", String::from("<unset>"), &Source::Default),
+        }
+        match &self.profile {
+            Some(profile) => row("profile", quote(&profile.value), &profile.source),
+            None => row("profile", String::from("<unset>"), &Source::Default),
+        }
+        for (name, profile) in &self.profiles {
+            let (key, value, source) = session_row(&format!("profiles.{name}."), &profile.session);
+            row(&key, value, source);
+        }
+
+        let width = rows.iter().map(|(k, v, _)| k.len() + v.chars().count() + 3);
+        let width = width.max().unwrap_or(0);
+        let mut s = String::new();
+        for (key, value, source) in rows {
+            let line = format!("{key} = {value}");
+            let pad = width - line.chars().count();
+            s.push_str(&format!("{line}{:pad$}  # {source}\n", ""));
+        }
+        s
+    }
+}
+
+impl<T> Sourced<T> {
+    fn new(value: T, source: Source) -> Self {
+        Self { value, source }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => f.write_str("default"),
+            Self::File(path) => {
+                let workspace_dir = workspace_dir();
+                let path = path.strip_prefix(&workspace_dir).unwrap_or(path);
+                write!(f, "{}", path.display())
+            }
+            Self::Env(var) => write!(f, "env {var}"),
+        }
+    }
+}
+
+/// Returns the key, value and source of a session for showing it.
+fn session_row<'a>(
+    prefix: &str,
+    session: &'a Option<Sourced<Session>>,
+) -> (String, String, &'a Source) {
+    match session {
+        Some(Sourced {
+            value: Session::Token(token),
+            source,
+        }) => (format!("{prefix}session"), quote(&mask(token)), source),
+        Some(Sourced {
+            value: Session::File(path),
+            source,
+        }) => (
+            format!("{prefix}session-file"),
+            quote(&path.display()),
+            source,
+        ),
+        None => (
+            format!("{prefix}session"),
+            String::from("<unset>"),
+            &Source::Default,
+        ),
+    }
+}
+
+/// Expands a leading `~` to the home directory.
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), env::var_os("HOME")) {
+        (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => path.to_owned(),
+    }
+}
+
+/// Hides all but the start of a secret.
+fn mask(secret: &str) -> String {
+    let start: String = secret.chars().take(4).collect();
+    format!("{start}…")
+}
+
+fn quote(s: &dyn fmt::Display) -> String {
+    format!("{:?}", s.to_string())
+}
+
+fn list(items: &[String]) -> String {
+    let items: Vec<_> = items.iter().map(|s| quote(s)).collect();
+    format!("[{}]", items.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(s: &str) -> Layer {
+        toml::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn layers_override() {
+        let user = layer(
+            r#"
+session-file = "~/.config/advent/session"
+browser = "chromium"
+
+[args]
+cargo = ["--features", "json"]
+"#,
+        );
+        let workspace = layer(
+            r#"
+output = "boring"
+
+[input]
+path = "{year}/day{day}.txt"
+
+[current]
+year = 2023
+day = 17
+"#,
+        );
+        let config = Config::from_layers(
+            vec![
+                (PathBuf::from("/home/user/config.toml"), user),
+                (PathBuf::from("/work/.advent.toml"), workspace),
+            ],
+            |_| None,
+        );
+
+        let user = Source::File(PathBuf::from("/home/user/config.toml"));
+        let workspace = Source::File(PathBuf::from("/work/.advent.toml"));
+        assert_eq!(
+            config.session,
+            Some(Sourced {
+                value: Session::File(PathBuf::from("~/.config/advent/session")),
+                source: user.clone(),
+            })
+        );
+        assert_eq!(config.browser.value, "chromium");
+        assert_eq!(config.browser.source, user);
+        assert_eq!(config.user_agent.source, Source::Default);
+        assert_eq!(config.output.as_ref().unwrap().source, workspace);
+        assert_eq!(config.cargo_args.value, ["--features", "json"]);
+        assert_eq!(config.bin_args.source, Source::Default);
+        assert_eq!(
+            config.input_relative(2023, 1),
+            PathBuf::from("2023/day01.txt")
+        );
+        assert_eq!(
+            config.current.unwrap().value,
+            Current {
+                year: 2023,
+                day: 17
+            }
+        );
+    }
+
+    #[test]
+    fn env_overrides_session() {
+        let config = Config::from_layers(
+            vec![(PathBuf::from("/a.toml"), layer(r#"session = "abc""#))],
+            |k| (k == "ADVENT_SESSION").then(|| String::from("xyz")),
+        );
+        assert_eq!(config.session().unwrap(), "xyz");
+        assert_eq!(
+            config.session.unwrap().source,
+            Source::Env("ADVENT_SESSION")
+        );
+    }
+
+    #[test]
+    fn env_overrides_base_url() {
+        let config = Config::from_layers(
+            vec![(
+                PathBuf::from("/a.toml"),
+                layer(r#"base-url = "http://localhost:1""#),
+            )],
+            |k| (k == "ADVENT_BASE_URL").then(|| String::from("http://127.0.0.1:8080/")),
+        );
+        assert_eq!(config.base_url.value, "http://127.0.0.1:8080");
+        assert_eq!(config.base_url.source, Source::Env("ADVENT_BASE_URL"));
+    }
+
+    #[test]
+    fn profiles() {
+        let config = Config::from_layers(
+            vec![
+                (
+                    PathBuf::from("/a.toml"),
+                    layer(
+                        r#"
+session = "abc"
+
+[profiles.alice]
+session = "alice123"
+
+[profiles.bob]
+session = "bob123"
+"#,
+                    ),
+                ),
+                (
+                    PathBuf::from("/b.toml"),
+                    layer("profile = \"alice\"\n[profiles.bob]\nsession-file = \"/bob\""),
+                ),
+            ],
+            |k| (k == "ADVENT_SESSION").then(|| String::from("xyz")),
+        );
+        assert_eq!(config.profile.as_ref().unwrap().value, "alice");
+        assert_eq!(config.session().unwrap(), "alice123");
+        assert!(config.input_dir().ends_with("input/alice"));
+        assert_eq!(
+            config.profiles["bob"].session,
+            Some(Sourced {
+                value: Session::File(PathBuf::from("/bob")),
+                source: Source::File(PathBuf::from("/b.toml")),
+            })
+        );
+
+        // The environment only overrides the top level session
+        let config = config.with_profile(None);
+        assert_eq!(config.session().unwrap(), "xyz");
+        assert!(config.input_dir().ends_with("input"));
+
+        let config = config.with_profile(Some("carol"));
+        assert_eq!(
+            config.session().unwrap_err().to_string(),
+            "profile `carol` must have a `session` or `session-file` config"
+        );
+    }
+
+    #[test]
+    fn unknown_keys_rejected() {
+        assert!(toml::from_str::<Layer>("sesion = \"abc\"").is_err());
+    }
+
+    #[test]
+    fn show_sources() {
+        let config = Config::from_layers(
+            vec![(
+                PathBuf::from("/a.toml"),
+                layer("session = \"533abcdef\"\nbrowser = \"chromium\""),
+            )],
+            |_| None,
+        );
+        assert_eq!(
+            config.show(),
+            r#"session = "533a…"                                                    # /a.toml
+base-url = "https://adventofcode.com"                                # default
+browser = "chromium"                                                 # /a.toml
+user-agent = "github.com/rossmacarthur/advent by ross@macarthur.io"  # default
+output = <unset>                                                     # default
+input.dir = "input"                                                  # default
+input.path = "{year}/{day}.txt"                                      # default
+args.cargo = []                                                      # default
+args.bin = []                                                        # default
+current = <unset>                                                    # default
+profile = <unset>                                                    # default
+"#
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! Run the solutions against the input of every profile.
+//!
+//! A solution that works for one input can rely on something that is not true
+//! for every input, e.g. the layout of the cube in 2022 day 22. Each answer is
+//! compared against the correct answer submitted using that profile.
+
+use std::iter;
+use std::process;
+
+use anyhow::Result;
+
+use crate::batch::{self, answer_cell, day_cell, failed_row, Puzzle, Run, Summary, FAILED};
+use crate::config::{self, Config};
+use crate::table::{Cell, Style, Table};
+use crate::{check_profile_input, print, split_args, submit, warning};
+
+/// The outcome of running a solution with the input of a single profile.
+#[derive(Debug)]
+struct Outcome {
+    puzzle: Puzzle,
+    profile: String,
+    runs: Option<Vec<Run>>,
+}
+
+pub fn crosscheck(puzzles: &[Puzzle], args: &[String]) -> Result<()> {
+    let config = config::get();
+    if config.profiles.is_empty() {
+        warning(
+            "Missing",
+            "profiles in the config, only the default input will be checked",
+        );
+    }
+    let profiles: Vec<_> = iter::once(None)
+        .chain(config.profiles.keys().map(|name| Some(name.as_str())))
+        .collect();
+
+    let mut inputs = Vec::new();
+    for p in puzzles {
+        for profile in &profiles {
+            let config = config.with_profile(*profile);
+            let name = profile.unwrap_or("default");
+            if !config.input(p.year, p.day).exists() && config.session().is_err() {
+                warning(
+                    "Skipped",
+                    format!(
+                        "profile `{name}` has no session or input for puzzle \
+                         (year: {:04}, day: {:02})",
+                        p.year, p.day
+                    ),
+                );
+                continue;
+            }
+            check_profile_input(&config, p.year, p.day)?;
+            inputs.push((*p, name, config));
+        }
+    }
+
+    let (cargo_args, bin_args) = split_args(args);
+    let bins: Vec<_> = puzzles.iter().map(Puzzle::bin_name).collect();
+    let release_dir = batch::build(&bins, &cargo_args)?;
+
+    let mut outcomes = Vec::new();
+    for (p, name, config) in &inputs {
+        let bin = p.bin_name();
+        print("Running", format!("`{bin}` with profile `{name}`"));
+        let output = process::Command::new(release_dir.join(&bin))
+            .envs(config.input_env())
+            .args(&bin_args)
+            .args(["--output", "json"])
+            .stderr(process::Stdio::null())
+            .output()?;
+        let runs = match serde_json::from_slice(&output.stdout) {
+            Ok(Summary::Run(runs)) if output.status.success() => {
+                Some(runs.into_iter().filter(Run::is_part).collect())
+            }
+            _ => None,
+        };
+        outcomes.push(Outcome {
+            puzzle: *p,
+            profile: name.to_string(),
+            runs: runs.map(|runs| with_answers(config, runs, p)).transpose()?,
+        });
+    }
+
+    println!();
+    print!("{}", render(&outcomes, Style::stdout()));
+    let answers = outcomes
+        .iter()
+        .map(|o| o.runs.as_ref().map_or(1, Vec::len))
+        .sum::<usize>();
+    let failures = outcomes
+        .iter()
+        .map(|o| match &o.runs {
+            Some(runs) => runs.iter().filter(|r| is_failure(r)).count(),
+            None => 1,
+        })
+        .sum::<usize>();
+    if failures > 0 {
+        warning("Failed", format!("{failures} of {answers} answers"));
+        process::exit(1);
+    }
+    Ok(())
+}
+
+/// Sets the expected answers to the ones recorded for the profile.
+///
+/// The answers given by the solution itself are only kept for the default
+/// profile since they are for the default input.
+fn with_answers(config: &Config, mut runs: Vec<Run>, p: &Puzzle) -> Result<Vec<Run>> {
+    let answers = submit::correct_answers(config, p.year, p.day)?;
+    for (run, answer) in runs.iter_mut().zip(answers) {
+        if answer.is_some() || config.profile.is_some() {
+            run.expected = answer;
+        }
+    }
+    Ok(runs)
+}
+
+fn is_failure(run: &Run) -> bool {
+    run.result == FAILED || run.is_correct() == Some(false)
+}
+
+fn render(outcomes: &[Outcome], style: Style) -> String {
+    let mut table = Table::new(&["Year", "Day", "Profile", "Part 1", "Part 2"]);
+    for o in outcomes {
+        let mut row = vec![
+            Cell::Plain(o.puzzle.year.to_string()),
+            day_cell(&o.puzzle),
+            Cell::Plain(o.profile.clone()),
+        ];
+        match &o.runs {
+            Some(runs) => {
+                for i in 0..2 {
+                    row.push(match runs.get(i) {
+                        Some(run) => answer_cell(run),
+                        None => Cell::Dim("-".into()),
+                    });
+                }
+            }
+            None => row.extend(failed_row(2)),
+        }
+        table.row(row);
+    }
+    table.render(style)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::batch::Kind;
+
+    fn run(result: &str, expected: Option<&str>) -> Run {
+        Run {
+            name: String::new(),
+            kind: Kind::Part,
+            result: result.into(),
+            elapsed: 0.0,
+            expected: expected.map(String::from),
+        }
+    }
+
+    #[test]
+    fn render_outcomes() {
+        let puzzle = Puzzle {
+            year: 2022,
+            day: 22,
+        };
+        let outcomes = [
+            Outcome {
+                puzzle,
+                profile: "default".into(),
+                runs: Some(vec![
+                    run("1428", Some("1428")),
+                    run("142380", Some("142380")),
+                ]),
+            },
+            Outcome {
+                puzzle,
+                profile: "alice".into(),
+                runs: Some(vec![run("6032", Some("6032")), run(FAILED, Some("5031"))]),
+            },
+            Outcome {
+                puzzle,
+                profile: "bob".into(),
+                runs: Some(vec![run("189140", Some("189140")), run("115063", None)]),
+            },
+            Outcome {
+                puzzle,
+                profile: "carol".into(),
+                runs: None,
+            },
+        ];
+        assert_eq!(
+            render(&outcomes, Style::Plain),
+            "\
+Year  Day  Profile  Part 1    Part 2
+2022  22   default  1428 ✓    142380 ✓
+2022  22   alice    6032 ✓    🚨👻🚨
+2022  22   bob      189140 ✓  115063
+2022  22   carol    🚨👻🚨
+"
+        );
+        assert!(is_failure(&run(FAILED, None)));
+        assert!(is_failure(&run("1", Some("2"))));
+        assert!(!is_failure(&run("1", None)));
+    }
+}
@@ -0,0 +1,406 @@
+//! Diagnose common problems with the environment.
+//!
+//! Each check reports whether it passed, or a warning or an error along with a
+//! suggested fix. The command fails if any check has an error.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use anyhow::{Context, Result};
+use argh::FromArgs;
+use time::OffsetDateTime;
+
+use crate::batch::parse_bin_name;
+use crate::calendar::{self, FIRST_YEAR};
+use crate::config::{self, Config};
+use crate::manifest::{Binary, Manifest};
+use crate::{failure, git, http, parse_args, print, warning};
+
+/// Check the workspace, session, inputs and solution binaries for problems.
+#[derive(Debug, FromArgs)]
+struct Opt {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Ok,
+    Warning,
+    Error,
+}
+
+/// The outcome of a single check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Check {
+    status: Status,
+    name: &'static str,
+    message: String,
+    fix: Option<String>,
+}
+
+impl Check {
+    fn ok(name: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            status: Status::Ok,
+            name,
+            message: message.into(),
+            fix: None,
+        }
+    }
+
+    fn warning(name: &'static str, message: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            status: Status::Warning,
+            name,
+            message: message.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn error(name: &'static str, message: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            status: Status::Error,
+            name,
+            message: message.into(),
+            fix: Some(fix.into()),
+        }
+    }
+}
+
+pub fn doctor(args: &[String]) -> Result<()> {
+    let Opt {} = parse_args("doctor", args);
+
+    let workspace_dir = config::workspace_dir();
+    let mut checks = vec![workspace(&workspace_dir)];
+
+    // The session and inputs can only be checked with a valid config
+    match config::init() {
+        Ok(()) => {
+            let config = config::get();
+            checks.push(session(config));
+            checks.push(inputs(config, &workspace_dir));
+        }
+        Err(err) => checks.push(Check::error(
+            "Config",
+            format!("{err:#}"),
+            "fix the config file named in the error",
+        )),
+    }
+
+    match Manifest::workspace() {
+        Ok(manifest) => checks.extend(binaries(&workspace_dir, &manifest.bins())),
+        Err(err) => checks.push(Check::error(
+            "Binaries",
+            format!("{err:#}"),
+            "run the CLI from an Advent workspace",
+        )),
+    }
+
+    for check in &checks {
+        match check.status {
+            Status::Ok => print(check.name, &check.message),
+            Status::Warning => warning(check.name, &check.message),
+            Status::Error => failure(check.name, &check.message),
+        }
+        if let Some(fix) = &check.fix {
+            println!("{:>12} fix: {fix}", "");
+        }
+    }
+
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    let (warnings, errors) = (count(Status::Warning), count(Status::Error));
+    let summary = format!(
+        "{} check(s), {warnings} warning(s), {errors} error(s)",
+        checks.len()
+    );
+    if errors > 0 {
+        failure("Finished", summary);
+        process::exit(1);
+    } else if warnings > 0 {
+        warning("Finished", summary);
+    } else {
+        print("Finished", summary);
+    }
+    Ok(())
+}
+
+/// Checks that the workspace directory is given by Cargo and has a manifest.
+fn workspace(workspace_dir: &Path) -> Check {
+    let display = workspace_dir.display();
+    if !workspace_dir.join("Cargo.toml").exists() {
+        return Check::error(
+            "Workspace",
+            format!("`{display}` does not have a `Cargo.toml`"),
+            "set `CARGO_WORKSPACE_DIR` to the workspace or run the CLI using `cargo advent`",
+        );
+    }
+    match env::var_os("CARGO_WORKSPACE_DIR") {
+        Some(dir) if !dir.is_empty() => Check::ok("Workspace", format!("`{display}`")),
+        _ => Check::warning(
+            "Workspace",
+            format!(
+                "`CARGO_WORKSPACE_DIR` is not set, using `{display}` from when the CLI was built"
+            ),
+            "run the CLI using `cargo advent` which sets it in `.cargo/config.toml`",
+        ),
+    }
+}
+
+/// Checks that the session is set and still logged in by fetching the
+/// personal leaderboard page, which is small and requires a session.
+fn session(config: &Config) -> Check {
+    const FIX: &str = "log in to the website and copy the `session` cookie to `ADVENT_SESSION` or the `session` or `session-file` config";
+
+    let client = match http::Client::with_config(config) {
+        Ok(client) => client,
+        Err(err) => return Check::error("Session", format!("{err:#}"), FIX),
+    };
+    let source = match &config.profile {
+        Some(profile) => format!("profile `{}`", profile.value),
+        None => match &config.session {
+            Some(session) => session.source.to_string(),
+            None => String::from("default"),
+        },
+    };
+    let year = calendar::latest(OffsetDateTime::now_utc()).year;
+    match client.get(&format!("/{year}/leaderboard/self")) {
+        Ok(html) => match logged_in_user(&html) {
+            Some(user) => Check::ok("Session", format!("logged in as `{user}` using {source}")),
+            None => Check::error(
+                "Session",
+                format!("the session from {source} is not logged in, it may have expired"),
+                FIX,
+            ),
+        },
+        Err(err) => Check::error(
+            "Session",
+            format!("{err:#}"),
+            format!("if the session from {source} has expired, {FIX}"),
+        ),
+    }
+}
+
+/// Returns the name of the user shown in the header of a page, or `None` if
+/// the page was not fetched with a logged in session.
+fn logged_in_user(html: &str) -> Option<String> {
+    let (_, rest) = html.split_once("<div class=\"user\">")?;
+    let name = rest[..rest.find('<')?].trim();
+    match name.is_empty() {
+        true => None,
+        false => Some(name.to_owned()),
+    }
+}
+
+/// Checks that the input directory is a git checkout of its own, so that the
+/// inputs can be kept in a private repository.
+fn inputs(config: &Config, workspace_dir: &Path) -> Check {
+    let dir = config.input_dir();
+    let display = dir.strip_prefix(workspace_dir).unwrap_or(&dir).display();
+    if !dir.exists() {
+        return Check::warning(
+            "Inputs",
+            format!("`{display}` does not exist"),
+            format!("clone your inputs repository to `{display}`, or add a solution using `cargo advent new`"),
+        );
+    }
+    let toplevels = git::toplevel(&dir).and_then(|t| Ok((t, git::toplevel(workspace_dir)?)));
+    match toplevels {
+        Ok((toplevel, workspace)) if toplevel.is_none() || toplevel == workspace => Check::warning(
+            "Inputs",
+            format!("`{display}` is not a git checkout, the inputs are not backed up"),
+            format!("run `git init` in `{display}` and push it to a private repository"),
+        ),
+        Ok(_) => Check::ok("Inputs", format!("`{display}` is a git checkout")),
+        Err(err) => Check::error(
+            "Inputs",
+            format!("{err:#}"),
+            format!("check that `git` is installed and `{display}` is readable"),
+        ),
+    }
+}
+
+/// Checks that each `[[bin]]` in the manifest has a source file and that each
+/// solution source file has a `[[bin]]`.
+fn binaries(workspace_dir: &Path, bins: &[Binary]) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    for bin in bins {
+        if workspace_dir.join(&bin.path).exists() {
+            continue;
+        }
+        let fix = match parse_bin_name(&bin.name) {
+            Some(p) => format!(
+                "run `cargo advent -y {} -d {} remove` to remove the binary",
+                p.year, p.day
+            ),
+            None => String::from("remove the `[[bin]]` from `Cargo.toml`"),
+        };
+        checks.push(Check::error(
+            "Binaries",
+            format!(
+                "`{}` is missing its source `{}`",
+                bin.name,
+                bin.path.display()
+            ),
+            fix,
+        ));
+    }
+
+    match sources_on_disk(workspace_dir) {
+        Ok(sources) => {
+            for (year, day, path) in sources {
+                if bins.iter().any(|b| b.path == path) {
+                    continue;
+                }
+                checks.push(Check::warning(
+                    "Binaries",
+                    format!("`{}` does not have a `[[bin]]`", path.display()),
+                    format!("run `cargo advent -y {year} -d {day} new` to add the binary"),
+                ));
+            }
+        }
+        Err(err) => checks.push(Check::error(
+            "Binaries",
+            format!("{err:#}"),
+            "check that the workspace and its year directories are readable",
+        )),
+    }
+
+    if checks.is_empty() {
+        checks.push(Check::ok(
+            "Binaries",
+            format!("{} binaries in sync with the solution sources", bins.len()),
+        ));
+    }
+    checks
+}
+
+/// Returns each `YYYY/DD.rs` solution source in the workspace, relative to it.
+fn sources_on_disk(workspace_dir: &Path) -> Result<Vec<(u32, u32, PathBuf)>> {
+    let mut sources = Vec::new();
+    let read_dir = |dir: &Path| {
+        fs::read_dir(dir).with_context(|| format!("failed to read `{}`", dir.display()))
+    };
+    for entry in read_dir(workspace_dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Some(year) = parse_number(&name, 4).filter(|y| *y >= FIRST_YEAR) else {
+            continue;
+        };
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        for entry in read_dir(&entry.path())? {
+            let name = entry?.file_name().to_string_lossy().into_owned();
+            let Some(day) = name
+                .strip_suffix(".rs")
+                .and_then(|d| parse_number(d, 2))
+                .filter(|d| (1..=calendar::last_day(year)).contains(d))
+            else {
+                continue;
+            };
+            sources.push((year, day, PathBuf::from(format!("{year:04}/{day:02}.rs"))));
+        }
+    }
+    sources.sort();
+    Ok(sources)
+}
+
+/// Parses a number with exactly the given number of digits.
+fn parse_number(s: &str, digits: usize) -> Option<u32> {
+    match s.len() == digits && s.bytes().all(|b| b.is_ascii_digit()) {
+        true => s.parse().ok(),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::http::tests::temp_dir;
+
+    fn bin(name: &str, path: &str) -> Binary {
+        Binary {
+            name: name.into(),
+            path: path.into(),
+        }
+    }
+
+    #[test]
+    fn logged_in_user_from_header() {
+        let html = include_str!("../fixtures/leaderboard-self.html");
+        assert_eq!(logged_in_user(html).as_deref(), Some("example"));
+        assert_eq!(logged_in_user("<div class=\"user\"></div>"), None);
+        assert_eq!(logged_in_user("<p>[Log In]</p>"), None);
+    }
+
+    #[test]
+    fn binaries_out_of_sync() {
+        let dir = temp_dir("doctor-binaries");
+        for path in [
+            "2022/25.rs",
+            "2023/01.rs",
+            "2023/02.rs",
+            "2023/prelude.rs",
+            "2023/26.rs",
+            "202/01.rs",
+        ] {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+
+        let bins = [
+            bin("202301", "2023/01.rs"),
+            bin("202302", "2023/02.rs"),
+            bin("202303", "2023/03.rs"),
+            bin("tool", "src/tool.rs"),
+        ];
+        let checks = binaries(&dir, &bins);
+        assert_eq!(
+            checks,
+            [
+                Check::error(
+                    "Binaries",
+                    "`202303` is missing its source `2023/03.rs`",
+                    "run `cargo advent -y 2023 -d 3 remove` to remove the binary"
+                ),
+                Check::error(
+                    "Binaries",
+                    "`tool` is missing its source `src/tool.rs`",
+                    "remove the `[[bin]]` from `Cargo.toml`"
+                ),
+                Check::warning(
+                    "Binaries",
+                    "`2022/25.rs` does not have a `[[bin]]`",
+                    "run `cargo advent -y 2022 -d 25 new` to add the binary"
+                ),
+            ]
+        );
+
+        let checks = binaries(
+            &dir,
+            &[
+                bin("202225", "2022/25.rs"),
+                bins[0].clone(),
+                bins[1].clone(),
+            ],
+        );
+        assert_eq!(
+            checks,
+            [Check::ok(
+                "Binaries",
+                "3 binaries in sync with the solution sources"
+            )]
+        );
+        fs::remove_dir_all(&dir).unwrap();
+
+        // An unreadable workspace is reported instead of aborting the checks
+        let checks = binaries(&dir, &[bins[0].clone()]);
+        assert_eq!(checks.len(), 2);
+        assert_eq!(checks[0].status, Status::Error);
+        assert_eq!(checks[1].status, Status::Error);
+        assert!(checks[1].message.starts_with("failed to read `"));
+    }
+}
@@ -0,0 +1,327 @@
+//! Extract the title and example inputs from the puzzle page.
+
+use std::fs;
+
+use anyhow::{bail, Result};
+use argh::FromArgs;
+
+use crate::http::Client;
+use crate::{config, parse_args, print, warning};
+
+/// Download the puzzle page and save each example block.
+#[derive(Debug, FromArgs)]
+struct Opt {
+    /// insert the example with the given number into the solution tests, can
+    /// be repeated
+    #[argh(option)]
+    insert: Vec<usize>,
+}
+
+pub fn examples(year: u32, day: u32, args: &[String]) -> Result<()> {
+    let Opt { insert } = parse_args("examples", args);
+
+    let examples = save(year, day, &page(year, day)?)?;
+    if insert.is_empty() {
+        return Ok(());
+    }
+
+    let mut selected = Vec::new();
+    for n in insert {
+        match examples.get(n.wrapping_sub(1)) {
+            Some(example) => selected.push((n, example.as_str())),
+            None => bail!("there is no example {n}, found {}", examples.len()),
+        }
+    }
+
+    let workspace_dir = config::workspace_dir();
+    let bin = workspace_dir.join(format!("{year:04}/{day:02}.rs"));
+    let bin_display = bin.strip_prefix(&workspace_dir).unwrap_or(&bin).display();
+    let source = fs::read_to_string(&bin)?;
+    match insert_tests(&source, &selected) {
+        Some(source) => {
+            fs::write(&bin, source)?;
+            print(
+                "Updated",
+                format!("binary source `{bin_display}` with examples"),
+            );
+        }
+        None => warning(
+            "Skipped",
+            format!("binary source `{bin_display}` already has examples"),
+        ),
+    }
+
+    Ok(())
+}
+
+/// Downloads the puzzle page.
+pub fn page(year: u32, day: u32) -> Result<String> {
+    Client::new()?.get(&format!("/{year}/day/{day}"))
+}
+
+/// Saves each example block in the puzzle page, returning them.
+pub fn save(year: u32, day: u32, html: &str) -> Result<Vec<String>> {
+    let examples = extract(html);
+
+    let workspace_dir = config::workspace_dir();
+    for (i, example) in examples.iter().enumerate() {
+        let path = config::get().input_sibling(year, day, &format!("example-{}.txt", i + 1));
+        let path_display = path.strip_prefix(&workspace_dir).unwrap_or(&path).display();
+        if fs::read_to_string(&path).is_ok_and(|s| s == *example) {
+            print("Verified", format!("example input `{path_display}`"));
+        } else {
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(&path, example)?;
+            print("Extracted", format!("example input `{path_display}`"));
+        }
+    }
+    if examples.is_empty() {
+        warning(
+            "Missing",
+            format!("example inputs (year: {year:04}, day: {day:02})"),
+        );
+    }
+
+    Ok(examples)
+}
+
+/// Extracts the puzzle title from the heading, e.g. `Trebuchet?!` from
+/// `--- Day 1: Trebuchet?! ---`.
+pub fn title(html: &str) -> Option<String> {
+    let start = html.find("<h2")?;
+    let start = start + html[start..].find('>')? + 1;
+    let end = start + html[start..].find("</h2>")?;
+    let heading = decode_entities(&strip_tags(&html[start..end]));
+    let heading = heading.trim().trim_matches('-').trim();
+    let (_, title) = heading.split_once(": ")?;
+    Some(title.to_owned())
+}
+
+/// Extracts the text of each `<pre><code>` block in the page.
+fn extract(html: &str) -> Vec<String> {
+    const START: &str = "<pre><code>";
+    const END: &str = "</code></pre>";
+
+    let mut examples = Vec::new();
+    let mut rest = html;
+    while let Some(i) = rest.find(START) {
+        rest = &rest[i + START.len()..];
+        let Some(j) = rest.find(END) else {
+            break;
+        };
+        examples.push(decode_entities(&strip_tags(&rest[..j])));
+        rest = &rest[j + END.len()..];
+    }
+    examples
+}
+
+/// Removes any tags, e.g. `<em>` used to highlight parts of an example.
+pub fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = text;
+    while let Some(i) = rest.find('&') {
+        decoded.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let entity = rest
+            .find(';')
+            .map(|j| (&rest[1..j], j))
+            .and_then(|(name, j)| Some((decode_entity(name)?, j)));
+        match entity {
+            Some((c, j)) => {
+                decoded.push(c);
+                rest = &rest[j + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let n = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(n)
+        }
+    }
+}
+
+/// Inserts an entry into the `advent::tests!` invocation for each example
+/// along with a constant containing the example input.
+///
+/// Returns `None` if the source has no tests or already contains examples.
+fn insert_tests(source: &str, examples: &[(usize, &str)]) -> Option<String> {
+    const MACRO: &str = "advent::tests! {\n";
+
+    let mut i = source.find(MACRO)? + MACRO.len();
+    // The examples go after the `input` and `solve` headers
+    while let Some(line) = source[i..].split_inclusive('\n').next().filter(|line| {
+        let line = line.trim_start();
+        line.starts_with("input:") || line.starts_with("solve:")
+    }) {
+        i += line.len();
+    }
+    let names: Vec<_> = match examples {
+        [_] => vec![(String::from("example"), String::from("EXAMPLE"))],
+        _ => examples
+            .iter()
+            .map(|(n, _)| (format!("example{n}"), format!("EXAMPLE{n}")))
+            .collect(),
+    };
+    if names
+        .iter()
+        .any(|(_, konst)| source.contains(&format!("const {konst}: &str")))
+    {
+        return None;
+    }
+
+    let mut result = String::from(&source[..i]);
+    for (name, konst) in &names {
+        result.push_str(&format!("    {name}(parse_input({konst})) => [0, 0],\n"));
+    }
+    result.push_str(&source[i..]);
+    for ((_, konst), (_, example)) in names.iter().zip(examples) {
+        let escaped = example.replace('\\', "\\\\").replace('"', "\\\"");
+        // A string continuation would swallow any leading whitespace
+        let cont = if escaped.starts_with([' ', '\t']) {
+            ""
+        } else {
+            "\\\n"
+        };
+        result.push_str(&format!(
+            "\n#[cfg(test)]\nconst {konst}: &str = \"{cont}{}\";\n",
+            escaped.trim_end_matches('\n')
+        ));
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = include_str!("../fixtures/puzzle.html");
+    const PUZZLE_PART2: &str = include_str!("../fixtures/puzzle-part2.html");
+
+    #[test]
+    fn extract_puzzle() {
+        let examples = extract(PUZZLE);
+        assert_eq!(examples, ["1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n"]);
+    }
+
+    #[test]
+    fn extract_puzzle_part2() {
+        let examples = extract(PUZZLE_PART2);
+        assert_eq!(
+            examples,
+            [
+                "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n",
+                "#.#\n<->\n\"a\" & 'b'\n",
+                "two1nine\neightwothree\n",
+            ]
+        );
+    }
+
+    #[test]
+    fn title_heading() {
+        assert_eq!(title(PUZZLE).as_deref(), Some("Trebuchet?!"));
+        assert_eq!(
+            title("<h2>--- Day 5: If You Give A Seed A Fertilizer &amp; ---</h2>").as_deref(),
+            Some("If You Give A Seed A Fertilizer &")
+        );
+        assert_eq!(title("<p>Not found</p>"), None);
+    }
+
+    #[test]
+    fn decode_entities_basic() {
+        assert_eq!(decode_entities("a &lt;b&gt; &amp;&amp; c"), "a <b> && c");
+        assert_eq!(decode_entities("&#39;&#x41;&#X42;"), "'AB");
+        assert_eq!(decode_entities("R&D; & &unknown;"), "R&D; & &unknown;");
+    }
+
+    #[test]
+    fn insert_tests_single() {
+        let source =
+            "fn main() {}\n\nadvent::tests! {\n    input: \"2023/01\",\n    default => [1, 2],\n}\n";
+        let result = insert_tests(source, &[(1, "a\"b\\c\n1 2\n")]).unwrap();
+        assert_eq!(
+            result,
+            r#"fn main() {}
+
+advent::tests! {
+    input: "2023/01",
+    example(parse_input(EXAMPLE)) => [0, 0],
+    default => [1, 2],
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+a\"b\\c
+1 2";
+"#
+        );
+        assert_eq!(insert_tests(&result, &[(1, "x\n")]), None);
+    }
+
+    #[test]
+    fn insert_tests_multiple() {
+        let source = "advent::tests! {\n    default => [1, 2],\n}\n";
+        let result = insert_tests(source, &[(1, "a\n"), (3, "b\n")]).unwrap();
+        assert_eq!(
+            result,
+            r#"advent::tests! {
+    example1(parse_input(EXAMPLE1)) => [0, 0],
+    example3(parse_input(EXAMPLE3)) => [0, 0],
+    default => [1, 2],
+}
+
+#[cfg(test)]
+const EXAMPLE1: &str = "\
+a";
+
+#[cfg(test)]
+const EXAMPLE3: &str = "\
+b";
+"#
+        );
+    }
+
+    #[test]
+    fn insert_tests_leading_whitespace() {
+        let source = "advent::tests! {\n    default => [1, 2],\n}\n";
+        let result = insert_tests(source, &[(1, "    [D]\n[N] [C]\n")]).unwrap();
+        assert!(result.ends_with("const EXAMPLE: &str = \"    [D]\n[N] [C]\";\n"));
+    }
+
+    #[test]
+    fn insert_tests_no_macro() {
+        assert_eq!(insert_tests("fn main() {}\n", &[(1, "a\n")]), None);
+    }
+}
@@ -0,0 +1,114 @@
+//! Query the git repository containing the workspace.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use anyhow::{bail, Context, Result};
+
+use crate::config;
+
+/// A commit and whether there were uncommitted changes on top of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Head {
+    pub commit: String,
+    pub dirty: bool,
+}
+
+/// A worktree with a commit checked out, which is removed when dropped.
+#[derive(Debug)]
+pub struct Worktree {
+    pub path: PathBuf,
+}
+
+/// Returns the commit checked out in the workspace, or `None` if the workspace
+/// is not a git repository.
+pub fn head() -> Result<Option<Head>> {
+    let Some(commit) = git(&["rev-parse", "HEAD"])? else {
+        return Ok(None);
+    };
+    let status = git(&["status", "--porcelain", "--untracked-files=no"])?.unwrap_or_default();
+    Ok(Some(Head {
+        commit,
+        dirty: !status.is_empty(),
+    }))
+}
+
+/// Returns the commit hash for the revision, e.g. a branch, tag or `HEAD~1`.
+pub fn resolve(rev: &str) -> Result<String> {
+    match git(&[
+        "rev-parse",
+        "--verify",
+        "--quiet",
+        &format!("{rev}^{{commit}}"),
+    ])? {
+        Some(commit) => Ok(commit),
+        None => bail!("unknown git revision `{rev}`"),
+    }
+}
+
+impl Worktree {
+    /// Checks out the commit in a new worktree at the given path, replacing
+    /// any worktree left there before.
+    pub fn add(path: &Path, commit: &str) -> Result<Self> {
+        let path_str = path.to_string_lossy();
+        if path.exists() {
+            git(&["worktree", "remove", "--force", &path_str])?;
+            if path.exists() {
+                fs::remove_dir_all(path)?;
+            }
+        }
+        git(&["worktree", "prune"])?;
+        if git(&["worktree", "add", "--detach", "--quiet", &path_str, commit])?.is_none() {
+            bail!("failed to add git worktree for commit `{commit}`");
+        }
+        Ok(Self {
+            path: path.to_owned(),
+        })
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        let _ = git(&[
+            "worktree",
+            "remove",
+            "--force",
+            &self.path.to_string_lossy(),
+        ]);
+    }
+}
+
+/// Returns the top level directory of the git repository containing the
+/// directory, or `None` if it is not in a git repository.
+pub fn toplevel(dir: &Path) -> Result<Option<PathBuf>> {
+    Ok(git_in(dir, &["rev-parse", "--show-toplevel"])?.map(PathBuf::from))
+}
+
+/// Runs git in the workspace, returning the trimmed output or `None` if git is
+/// not installed or the command failed.
+fn git(args: &[&str]) -> Result<Option<String>> {
+    git_in(&config::workspace_dir(), args)
+}
+
+fn git_in(dir: &Path, args: &[&str]) -> Result<Option<String>> {
+    let output = match process::Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .stderr(process::Stdio::null())
+        .output()
+    {
+        Ok(output) => output,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err).context("failed to run git"),
+    };
+    if !output.status.success() {
+        return Ok(None);
+    }
+    match String::from_utf8(output.stdout) {
+        Ok(stdout) => Ok(Some(stdout.trim().to_owned())),
+        Err(_) => bail!("git output is not valid UTF-8"),
+    }
+}
@@ -0,0 +1,400 @@
+//! Record benchmark results and show how they change over commits.
+//!
+//! Each benchmark is appended as a line of JSON to `.advent/history.jsonl` in
+//! the workspace, tagged with the commit and the time it was run at.
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use argh::FromArgs;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::batch::{human_time, Bench, Puzzle};
+use crate::table::{Cell, Style, Table};
+use crate::{config, git, parse_args, print, warning};
+
+/// Times must be at least this much slower to be flagged as a regression.
+const THRESHOLD: f64 = 1.1;
+
+/// Show how the benchmark times changed over commits.
+#[derive(Debug, FromArgs)]
+struct Opt {
+    /// the number of most recent commits to show
+    #[argh(option, default = "20")]
+    limit: usize,
+}
+
+/// The benchmark results for a puzzle from a single run.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub commit: Option<String>,
+    #[serde(default)]
+    pub dirty: bool,
+    #[serde(with = "time::serde::rfc3339")]
+    pub time: OffsetDateTime,
+    pub benches: Vec<Bench>,
+}
+
+/// The best mean time of each benchmark at a commit.
+#[derive(Debug)]
+struct Point {
+    commit: String,
+    time: OffsetDateTime,
+    means: Vec<Option<f64>>,
+}
+
+/// Where a benchmark became slower.
+#[derive(Debug, PartialEq)]
+struct Regression {
+    name: String,
+    index: usize,
+    ratio: f64,
+}
+
+/// Returns the path to the history file.
+pub fn path() -> PathBuf {
+    config::workspace_dir()
+        .join(".advent")
+        .join("history.jsonl")
+}
+
+/// Appends the benchmark results to the history.
+pub fn record(results: &[(Puzzle, &[Bench])]) -> Result<()> {
+    if results.is_empty() {
+        return Ok(());
+    }
+    let head = git::head()?;
+    let time = OffsetDateTime::now_utc();
+
+    let path = path();
+    fs::create_dir_all(path.parent().unwrap())?;
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("failed to open `{}`", path.display()))?;
+    for (p, benches) in results {
+        let record = Record {
+            year: p.year,
+            day: p.day,
+            commit: head.as_ref().map(|h| h.commit.clone()),
+            dirty: head.as_ref().is_some_and(|h| h.dirty),
+            time,
+            benches: benches.to_vec(),
+        };
+        writeln!(file, "{}", serde_json::to_string(&record)?)?;
+    }
+
+    let commit = match &head {
+        Some(h) => format!(" for commit `{}`", short(&h.commit, h.dirty)),
+        None => String::new(),
+    };
+    let n = results.len();
+    let s = if n == 1 { "" } else { "s" };
+    print("Recorded", format!("{n} benchmark{s}{commit}"));
+    Ok(())
+}
+
+/// Loads every record in the history.
+pub fn load() -> Result<Vec<Record>> {
+    let path = path();
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("failed to parse `{}` line {}", path.display(), i + 1))
+        })
+        .collect()
+}
+
+pub fn history(year: u32, day: u32, args: &[String]) -> Result<()> {
+    let Opt { limit } = parse_args("history", args);
+
+    let records: Vec<_> = load()?
+        .into_iter()
+        .filter(|r| r.year == year && r.day == day)
+        .collect();
+    if records.is_empty() {
+        warning(
+            "Missing",
+            format!(
+                "benchmark history for puzzle (year: {year:04}, day: {day:02}), \
+                 run `cargo advent bench` first"
+            ),
+        );
+        return Ok(());
+    }
+
+    let (names, mut points) = points(&records);
+    points.drain(..points.len().saturating_sub(limit));
+    let regressions = regressions(&names, &points);
+    print!("{}", render(&names, &points, &regressions, Style::stdout()));
+    for r in &regressions {
+        let p = &points[r.index];
+        warning(
+            "Regression",
+            format!(
+                "{} is {:.0}% slower since `{}` on {}",
+                r.name,
+                (r.ratio - 1.0) * 100.0,
+                p.commit,
+                p.time.date()
+            ),
+        );
+    }
+    Ok(())
+}
+
+/// Groups the records by commit in the order they were first run, returning
+/// the benchmark names and the best time for each at each commit.
+fn points(records: &[Record]) -> (Vec<String>, Vec<Point>) {
+    let mut names: Vec<String> = Vec::new();
+    for r in records {
+        for b in &r.benches {
+            if !names.contains(&b.name) {
+                names.push(b.name.clone());
+            }
+        }
+    }
+
+    let mut points: Vec<Point> = Vec::new();
+    for r in records {
+        let commit = match &r.commit {
+            Some(commit) => short(commit, r.dirty),
+            None => "-".to_owned(),
+        };
+        let i = match points.iter().position(|p| p.commit == commit) {
+            Some(i) => i,
+            None => {
+                points.push(Point {
+                    commit,
+                    time: r.time,
+                    means: vec![None; names.len()],
+                });
+                points.len() - 1
+            }
+        };
+        for b in &r.benches {
+            let j = names.iter().position(|n| *n == b.name).unwrap();
+            let mean = &mut points[i].means[j];
+            *mean = Some(mean.map_or(b.stats.mean, |m| m.min(b.stats.mean)));
+        }
+    }
+    (names, points)
+}
+
+fn regressions(names: &[String], points: &[Point]) -> Vec<Regression> {
+    names
+        .iter()
+        .enumerate()
+        .filter_map(|(j, name)| {
+            let series: Vec<_> = points.iter().map(|p| p.means[j]).collect();
+            let (index, ratio) = regression(&series)?;
+            Some(Regression {
+                name: name.clone(),
+                index,
+                ratio,
+            })
+        })
+        .collect()
+}
+
+/// Finds the first point from which every time is slower than the best time
+/// before it, returning it along with how much slower the median time is.
+fn regression(series: &[Option<f64>]) -> Option<(usize, f64)> {
+    (1..series.len()).find_map(|k| {
+        series[k]?;
+        let best = series[..k].iter().flatten().copied().reduce(f64::min)?;
+        let mut after: Vec<_> = series[k..].iter().flatten().copied().collect();
+        after.sort_by(f64::total_cmp);
+        if after[0] < best * THRESHOLD {
+            return None;
+        }
+        Some((k, after[after.len() / 2] / best))
+    })
+}
+
+fn render(names: &[String], points: &[Point], regressions: &[Regression], style: Style) -> String {
+    let header: Vec<_> = ["Commit", "Date"]
+        .into_iter()
+        .chain(names.iter().map(String::as_str))
+        .collect();
+    let mut table = Table::new(&header);
+    for (i, p) in points.iter().enumerate() {
+        let mut row = vec![
+            Cell::Plain(p.commit.clone()),
+            Cell::Dim(p.time.date().to_string()),
+        ];
+        for (name, mean) in names.iter().zip(&p.means) {
+            let regressed = regressions.iter().any(|r| r.index == i && r.name == *name);
+            row.push(match mean {
+                Some(mean) if regressed => Cell::Bad(human_time(*mean)),
+                Some(mean) => Cell::Plain(human_time(*mean)),
+                None => Cell::Dim("-".into()),
+            });
+        }
+        table.row(row);
+    }
+
+    let mut out = table.render(style);
+    out.push('\n');
+    let width = names.iter().map(|n| n.chars().count()).max().unwrap_or(0);
+    for (j, name) in names.iter().enumerate() {
+        let series: Vec<_> = points.iter().map(|p| p.means[j]).collect();
+        let times: Vec<_> = series.iter().flatten().copied().collect();
+        let (Some(first), Some(last)) = (times.first(), times.last()) else {
+            continue;
+        };
+        out.push_str(&format!(
+            "{name:<width$}  {}  {} → {}\n",
+            sparkline(&series),
+            human_time(*first),
+            human_time(*last)
+        ));
+    }
+    out
+}
+
+/// Renders the times as bars scaled between the fastest and slowest.
+fn sparkline(series: &[Option<f64>]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let times = series.iter().flatten().copied();
+    let min = times.clone().fold(f64::INFINITY, f64::min);
+    let max = times.fold(f64::NEG_INFINITY, f64::max);
+    series
+        .iter()
+        .map(|t| match t {
+            None => ' ',
+            Some(_) if max <= min => BARS[0],
+            Some(t) => BARS[((t - min) / (max - min) * 7.0).round() as usize],
+        })
+        .collect()
+}
+
+/// Abbreviates the commit, marking it if there were uncommitted changes.
+fn short(commit: &str, dirty: bool) -> String {
+    let short = &commit[..commit.len().min(7)];
+    match dirty {
+        true => format!("{short}*"),
+        false => short.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use time::macros::datetime;
+
+    use crate::batch::{Kind, Stats};
+
+    fn record(commit: &str, dirty: bool, day: u8, means: &[(&str, f64)]) -> Record {
+        Record {
+            year: 2023,
+            day: 1,
+            commit: Some(commit.repeat(40 / commit.len())),
+            dirty,
+            time: datetime!(2023-12-01 0:00 UTC).replace_day(day).unwrap(),
+            benches: means
+                .iter()
+                .map(|(name, mean)| Bench {
+                    name: name.to_string(),
+                    kind: Kind::Part,
+                    stats: Stats {
+                        samples: 100,
+                        min: *mean,
+                        max: *mean,
+                        mean: *mean,
+                        std_dev: 0.0,
+                    },
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn record_json_roundtrip() {
+        let r = record("a", false, 1, &[("Part 1", 0.5)]);
+        let json = serde_json::to_string(&r).unwrap();
+        assert_eq!(
+            json,
+            r#"{"year":2023,"day":1,"commit":"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa","dirty":false,"time":"2023-12-01T00:00:00Z","benches":[{"name":"Part 1","kind":"part","samples":100,"min":0.5,"max":0.5,"mean":0.5,"std_dev":0.0}]}"#
+        );
+        let r: Record = serde_json::from_str(&json).unwrap();
+        assert_eq!(r.benches[0].stats.mean, 0.5);
+    }
+
+    #[test]
+    fn regression_start() {
+        let series = [
+            Some(1.0),
+            Some(1.02),
+            Some(0.99),
+            Some(1.5),
+            Some(1.6),
+            Some(1.55),
+        ];
+        let (index, ratio) = regression(&series).unwrap();
+        assert_eq!(index, 3);
+        assert!((ratio - 1.55 / 0.99).abs() < 1e-9);
+        // Noise and regressions that were fixed are not flagged
+        assert_eq!(regression(&[Some(1.0), Some(1.05), Some(0.97)]), None);
+        assert_eq!(regression(&[Some(1.0), Some(2.0), Some(1.0)]), None);
+        assert_eq!(
+            regression(&[None, Some(1.0), None, Some(2.0)]),
+            Some((3, 2.0))
+        );
+        assert_eq!(regression(&[Some(1.0)]), None);
+    }
+
+    #[test]
+    fn sparkline_scaled() {
+        assert_eq!(sparkline(&[Some(1.0), Some(2.0), None, Some(8.0)]), "▁▂ █");
+        assert_eq!(sparkline(&[Some(1.0), Some(1.0)]), "▁▁");
+    }
+
+    #[test]
+    fn render_history() {
+        let records = [
+            record("a", false, 1, &[("Part 1", 0.002), ("Part 2", 0.010)]),
+            record("a", false, 1, &[("Part 1", 0.001), ("Part 2", 0.011)]),
+            record("b", true, 2, &[("Part 1", 0.001), ("Part 2", 0.020)]),
+            record("c", false, 3, &[("Part 1", 0.001)]),
+            record("d", false, 4, &[("Part 1", 0.001), ("Part 2", 0.021)]),
+        ];
+        let (names, points) = points(&records);
+        let regressions = regressions(&names, &points);
+        assert_eq!(
+            regressions,
+            [Regression {
+                name: "Part 2".into(),
+                index: 1,
+                ratio: 0.021 / 0.010,
+            }]
+        );
+        assert_eq!(
+            render(&names, &points, &regressions, Style::Plain),
+            "\
+Commit    Date        Part 1   Part 2
+aaaaaaa   2023-12-01  1.00 ms  10.00 ms
+bbbbbbb*  2023-12-02  1.00 ms  20.00 ms
+ccccccc   2023-12-03  1.00 ms  -
+ddddddd   2023-12-04  1.00 ms  21.00 ms
+
+Part 1  ▁▁▁▁  1.00 ms → 1.00 ms
+Part 2  ▁▇ █  10.00 ms → 21.00 ms
+"
+        );
+    }
+}
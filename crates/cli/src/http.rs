@@ -0,0 +1,611 @@
+//! A shared client for all requests to Advent of Code.
+//!
+//! The client follows the [automation guidelines]:
+//!
+//! - requests are at least [`MIN_INTERVAL`] apart, tracked using a timestamp
+//!   in the cache directory so that this holds across invocations, except for
+//!   a server on the local machine, e.g. the mock server used in tests
+//! - rate limited requests are retried after the `Retry-After` duration
+//! - pages are cached and revalidated using ETags, or reused without a request
+//!   until they reach a maximum age
+//!
+//! Puzzle inputs are checked before being returned so that an error page is
+//! never saved as an input.
+//!
+//! [automation guidelines]: https://www.reddit.com/r/adventofcode/wiki/faqs/automation
+
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc2822;
+use time::OffsetDateTime;
+
+use crate::config::{self, Config};
+use crate::inputs::sha256;
+use crate::warning;
+
+/// The minimum time between requests.
+const MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// The number of times a rate limited request is retried.
+const MAX_RETRIES: usize = 3;
+
+/// The longest `Retry-After` that will be waited for before giving up.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(5 * 60);
+
+/// How long to wait when rate limited without a `Retry-After` header.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+pub struct Client {
+    base_url: String,
+    session: String,
+    user_agent: String,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+}
+
+#[derive(Debug)]
+struct Response {
+    status: u32,
+    etag: Option<String>,
+    retry_after: Option<Duration>,
+    body: String,
+}
+
+/// A cached page along with its ETag.
+#[derive(Debug, Deserialize, Serialize)]
+struct Cached {
+    etag: Option<String>,
+    body: String,
+}
+
+impl Client {
+    /// Returns a client using the configured base URL and session.
+    pub fn new() -> Result<Self> {
+        Self::with_config(config::get())
+    }
+
+    /// Returns a client using the base URL and session from the
+    /// configuration, e.g. for a profile other than the selected one.
+    pub fn with_config(config: &Config) -> Result<Self> {
+        Ok(Self {
+            base_url: config.base_url.value.clone(),
+            session: config.session()?,
+            user_agent: config.user_agent.value.clone(),
+            cache_dir: config::cache_dir(),
+            min_interval: match is_local(&config.base_url.value) {
+                true => Duration::ZERO,
+                false => MIN_INTERVAL,
+            },
+        })
+    }
+
+    /// Returns the full URL for the given path.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    /// Fetches a page, reusing the cached page if it has not changed.
+    pub fn get(&self, path: &str) -> Result<String> {
+        self.get_cached(path, None)
+    }
+
+    /// Fetches a page, reusing the cached page without a request if it is
+    /// younger than the given age.
+    pub fn get_within(&self, path: &str, max_age: Duration) -> Result<String> {
+        self.get_cached(path, Some(max_age))
+    }
+
+    fn get_cached(&self, path: &str, max_age: Option<Duration>) -> Result<String> {
+        let url = self.url(path);
+        let cache_path = self.cache_dir.join(cache_path(&self.session, &url));
+        let cached: Option<Cached> = fs::read_to_string(&cache_path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok());
+
+        if let (Some(cached), Some(max_age)) = (&cached, max_age) {
+            let age = fs::metadata(&cache_path)?.modified()?.elapsed();
+            if age.is_ok_and(|age| age < max_age) {
+                return Ok(cached.body.clone());
+            }
+        }
+
+        let etag = cached.as_ref().and_then(|c| c.etag.as_deref());
+        let response = self.send(&url, None, etag)?;
+        let cached = match (response.status, cached) {
+            (304, Some(cached)) => cached,
+            (200, _) => Cached {
+                etag: response.etag,
+                body: response.body,
+            },
+            (status, _) => return Err(error(&url, status, &response.body)),
+        };
+        // Pages with a maximum age are always cached, and rewritten after
+        // being revalidated to reset their age
+        if cached.etag.is_some() || max_age.is_some() {
+            fs::create_dir_all(cache_path.parent().unwrap())?;
+            fs::write(&cache_path, serde_json::to_string(&cached)?)?;
+        }
+        Ok(cached.body)
+    }
+
+    /// Posts a form and returns the response page.
+    pub fn post(&self, path: &str, form: &str) -> Result<String> {
+        let url = self.url(path);
+        let response = self.send(&url, Some(form), None)?;
+        match response.status {
+            200 => Ok(response.body),
+            status => Err(error(&url, status, &response.body)),
+        }
+    }
+
+    /// Fetches the puzzle input, refusing anything that is not an input.
+    pub fn input(&self, year: u32, day: u32) -> Result<String> {
+        let url = self.url(&format!("/{year}/day/{day}/input"));
+        let response = self.send(&url, None, None)?;
+        if response.status != 200 {
+            return Err(error(&url, response.status, &response.body));
+        }
+        if let Err(reason) = check_input(&response.body) {
+            bail!("refusing to save puzzle input (year: {year:04}, day: {day:02}), {reason}");
+        }
+        Ok(response.body)
+    }
+
+    /// Sends a request, retrying if rate limited.
+    fn send(&self, url: &str, form: Option<&str>, etag: Option<&str>) -> Result<Response> {
+        let mut retries = 0;
+        loop {
+            self.throttle()?;
+            let response = self.perform(url, form, etag)?;
+            let wait = match (response.status, response.retry_after) {
+                (429, wait) => wait.unwrap_or(DEFAULT_RETRY_AFTER),
+                (503, Some(wait)) => wait,
+                _ => return Ok(response),
+            };
+            if retries == MAX_RETRIES || wait > MAX_RETRY_AFTER {
+                bail!("rate limited by `{url}`, try again in {}s", wait.as_secs());
+            }
+            retries += 1;
+            warning(
+                "Waiting",
+                format!("{}s before retrying rate limited request", wait.as_secs()),
+            );
+            thread::sleep(wait);
+        }
+    }
+
+    /// Waits until the minimum interval since the last request has passed and
+    /// records the time of this request.
+    fn throttle(&self) -> Result<()> {
+        let path = self.cache_dir.join("last-request");
+        let last = fs::read_to_string(&path)
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+            .map(|ms| UNIX_EPOCH + Duration::from_millis(ms));
+        if let Some(last) = last {
+            let elapsed = SystemTime::now().duration_since(last).unwrap_or_default();
+            if let Some(wait) = self.min_interval.checked_sub(elapsed) {
+                thread::sleep(wait);
+            }
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+        fs::create_dir_all(&self.cache_dir)?;
+        fs::write(&path, now.to_string())?;
+        Ok(())
+    }
+
+    /// Performs a single GET request, or a POST request if a form is given.
+    fn perform(&self, url: &str, form: Option<&str>, etag: Option<&str>) -> Result<Response> {
+        let mut body = Vec::new();
+        let mut headers = Vec::new();
+        let mut easy = curl::easy::Easy::new();
+        easy.follow_location(true)?;
+        easy.useragent(&self.user_agent)?;
+        easy.cookie(&format!("session={}", self.session))?;
+        easy.url(url)?;
+        if let Some(etag) = etag {
+            let mut list = curl::easy::List::new();
+            list.append(&format!("If-None-Match: {etag}"))?;
+            easy.http_headers(list)?;
+        }
+        if let Some(form) = form {
+            easy.post(true)?;
+            easy.post_fields_copy(form.as_bytes())?;
+        }
+        {
+            let mut transfer = easy.transfer();
+            transfer.header_function(|header| {
+                let header = String::from_utf8_lossy(header);
+                // A redirect is followed by the headers of the next response
+                if header.starts_with("HTTP/") {
+                    headers.clear();
+                } else if let Some((name, value)) = header.split_once(':') {
+                    headers.push((name.trim().to_ascii_lowercase(), value.trim().to_owned()));
+                }
+                true
+            })?;
+            transfer.write_function(|data| {
+                body.extend_from_slice(data);
+                Ok(data.len())
+            })?;
+            transfer
+                .perform()
+                .with_context(|| format!("failed to request `{url}`"))?;
+        }
+
+        let header = |name: &str| {
+            headers
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, value)| value.as_str())
+        };
+        Ok(Response {
+            status: easy.response_code()?,
+            etag: header("etag").map(String::from),
+            retry_after: header("retry-after").and_then(parse_retry_after),
+            body: String::from_utf8(body)?,
+        })
+    }
+}
+
+/// Checks that the text looks like a puzzle input and not an error page.
+pub fn check_input(text: &str) -> Result<(), String> {
+    let start = text.trim_start().to_ascii_lowercase();
+    if text.trim().is_empty() {
+        Err("the response was empty".into())
+    } else if text.contains("Puzzle inputs differ by user") {
+        Err("puzzle inputs differ by user, check that the session is set".into())
+    } else if text.contains("Please log in") {
+        Err("the session is not logged in, check that it has not expired".into())
+    } else if text.contains("Please don't repeatedly request this endpoint before it unlocks") {
+        Err("the puzzle is not unlocked yet".into())
+    } else if start.starts_with("<!doctype") || start.starts_with("<html") {
+        Err("the response was an HTML page".into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns an error for an unsuccessful response.
+fn error(url: &str, status: u32, body: &str) -> anyhow::Error {
+    let reason = match check_input(body) {
+        Err(reason) => reason,
+        Ok(()) => {
+            let line = body.lines().next().unwrap_or_default().trim();
+            line.chars().take(80).collect()
+        }
+    };
+    anyhow!("request to `{url}` failed with status {status}, {reason}")
+}
+
+/// Parses a `Retry-After` header, either a number of seconds or a date.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    if let Ok(secs) = value.parse() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = OffsetDateTime::parse(value, &Rfc2822).ok()?;
+    Some(
+        (date - OffsetDateTime::now_utc())
+            .try_into()
+            .unwrap_or_default(),
+    )
+}
+
+/// Returns whether the URL is for a server on the local machine.
+fn is_local(url: &str) -> bool {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let host = rest.split('/').next().unwrap_or_default();
+    let host = match host.rsplit_once(':') {
+        Some((host, port)) if port.bytes().all(|b| b.is_ascii_digit()) => host,
+        _ => host,
+    };
+    matches!(host, "localhost" | "127.0.0.1" | "[::1]")
+}
+
+/// Returns the cache file for the URL, relative to the cache directory.
+///
+/// Pages like the leaderboards differ by user and every profile shares the
+/// cache directory, so each session is cached separately under its hash.
+fn cache_path(session: &str, url: &str) -> PathBuf {
+    let user = &sha256(session.as_bytes())[..16];
+    let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let key: String = url
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' => c,
+            _ => '_',
+        })
+        .collect();
+    ["http", user, &format!("{key}.json")].iter().collect()
+}
+
+#[cfg(test)]
+impl Client {
+    /// Returns a client for a local server that does not wait between
+    /// requests.
+    pub fn local(base_url: &str, cache_dir: PathBuf) -> Self {
+        Self {
+            base_url: base_url.to_owned(),
+            session: String::from("s3cr3t"),
+            user_agent: String::from("advent-test"),
+            cache_dir,
+            min_interval: Duration::ZERO,
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    use std::env;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::path::Path;
+    use std::process;
+    use std::time::Instant;
+
+    /// A request received by the local server.
+    #[derive(Debug)]
+    pub struct Request {
+        pub head: String,
+        pub body: String,
+    }
+
+    /// Returns an empty temporary directory for the test.
+    pub fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("advent-cli-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Renders a raw HTTP response.
+    pub fn response(status: &str, headers: &[&str], body: &str) -> String {
+        let mut s = format!("HTTP/1.1 {status}\r\n");
+        for header in headers {
+            s.push_str(&format!("{header}\r\n"));
+        }
+        s.push_str(&format!(
+            "Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        ));
+        s
+    }
+
+    /// Serves each response to one connection in order, returning the base URL
+    /// and a handle that returns the received requests.
+    pub fn serve(responses: Vec<String>) -> (String, thread::JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for response in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut head = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(n) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = n.trim().parse().unwrap();
+                    }
+                    if line == "\r\n" {
+                        break;
+                    }
+                    head.push_str(&line);
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+                requests.push(Request {
+                    head,
+                    body: String::from_utf8(body).unwrap(),
+                });
+            }
+            requests
+        });
+        (base_url, server)
+    }
+
+    #[test]
+    fn check_input_payloads() {
+        assert_eq!(check_input("1\n2\n3\n"), Ok(()));
+        assert_eq!(check_input("<x=1, y=2>\n"), Ok(()));
+        assert_eq!(check_input(" \n"), Err("the response was empty".into()));
+        assert_eq!(
+            check_input("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            Err("puzzle inputs differ by user, check that the session is set".into())
+        );
+        assert_eq!(
+            check_input("<p>Please log in.</p>"),
+            Err("the session is not logged in, check that it has not expired".into())
+        );
+        assert_eq!(
+            check_input("Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n"),
+            Err("the puzzle is not unlocked yet".into())
+        );
+        assert_eq!(
+            check_input("\n<!DOCTYPE html>\n<html><body>500</body></html>"),
+            Err("the response was an HTML page".into())
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_values() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+
+    #[test]
+    fn cache_path_url() {
+        let user = &sha256(b"s3cr3t")[..16];
+        assert_eq!(
+            cache_path("s3cr3t", "https://adventofcode.com/2023/day/1"),
+            Path::new("http")
+                .join(user)
+                .join("adventofcode.com_2023_day_1.json")
+        );
+        assert_eq!(
+            cache_path("s3cr3t", "http://127.0.0.1:8080/2023/day/1"),
+            Path::new("http")
+                .join(user)
+                .join("127.0.0.1_8080_2023_day_1.json")
+        );
+        assert_ne!(
+            cache_path("other", "http://127.0.0.1:8080/2023/day/1"),
+            cache_path("s3cr3t", "http://127.0.0.1:8080/2023/day/1"),
+        );
+    }
+
+    #[test]
+    fn is_local_url() {
+        assert!(is_local("http://127.0.0.1:8080"));
+        assert!(is_local("http://localhost/"));
+        assert!(is_local("http://[::1]:3000"));
+        assert!(!is_local("https://adventofcode.com"));
+        assert!(!is_local("https://localhost.example.com"));
+    }
+
+    #[test]
+    fn get_etag_cache() {
+        let (base_url, server) = serve(vec![
+            response("200 OK", &["ETag: \"v1\""], "<p>Day 1</p>"),
+            response("304 Not Modified", &["ETag: \"v1\""], ""),
+        ]);
+        let client = Client::local(&base_url, temp_dir("etag"));
+        assert_eq!(client.get("/2023/day/1").unwrap(), "<p>Day 1</p>");
+        assert_eq!(client.get("/2023/day/1").unwrap(), "<p>Day 1</p>");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].head.starts_with("GET /2023/day/1 HTTP/1.1\r\n"));
+        assert!(requests[0].head.contains("Cookie: session=s3cr3t\r\n"));
+        assert!(requests[0].head.contains("User-Agent: advent-test\r\n"));
+        assert!(!requests[0].head.contains("If-None-Match"));
+        assert!(requests[1].head.contains("If-None-Match: \"v1\"\r\n"));
+    }
+
+    #[test]
+    fn get_within_max_age() {
+        let (base_url, server) = serve(vec![
+            response("200 OK", &[], "{}"),
+            response("200 OK", &[], "{\"a\":1}"),
+        ]);
+        let client = Client::local(&base_url, temp_dir("max-age"));
+        let path = "/2023/leaderboard/private/view/1.json";
+        let max_age = Duration::from_secs(900);
+        assert_eq!(client.get_within(path, max_age).unwrap(), "{}");
+        assert_eq!(client.get_within(path, max_age).unwrap(), "{}");
+        assert_eq!(
+            client.get_within(path, Duration::ZERO).unwrap(),
+            "{\"a\":1}"
+        );
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn get_within_by_session() {
+        let (base_url, server) = serve(vec![
+            response("200 OK", &[], "{\"a\":1}"),
+            response("200 OK", &[], "{\"b\":2}"),
+        ]);
+        let dir = temp_dir("by-session");
+        let a = Client::local(&base_url, dir.clone());
+        let b = Client {
+            session: String::from("0th3r"),
+            ..Client::local(&base_url, dir)
+        };
+        let path = "/2023/leaderboard/private/view/1.json";
+        let max_age = Duration::from_secs(900);
+        assert_eq!(a.get_within(path, max_age).unwrap(), "{\"a\":1}");
+        assert_eq!(b.get_within(path, max_age).unwrap(), "{\"b\":2}");
+        assert_eq!(a.get_within(path, max_age).unwrap(), "{\"a\":1}");
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn send_retry_after() {
+        let (base_url, server) = serve(vec![
+            response("429 Too Many Requests", &["Retry-After: 1"], ""),
+            response("200 OK", &[], "1\n2\n"),
+        ]);
+        let client = Client::local(&base_url, temp_dir("retry"));
+        let start = Instant::now();
+        assert_eq!(client.input(2023, 1).unwrap(), "1\n2\n");
+        assert!(start.elapsed() >= Duration::from_secs(1));
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn send_retry_after_too_long() {
+        let (base_url, _server) = serve(vec![response(
+            "429 Too Many Requests",
+            &["Retry-After: 3600"],
+            "",
+        )]);
+        let client = Client::local(&base_url, temp_dir("retry-long"));
+        let err = client.get("/2023/day/1").unwrap_err();
+        assert!(err.to_string().starts_with("rate limited by"), "{err}");
+    }
+
+    #[test]
+    fn throttle_min_interval() {
+        let (base_url, server) = serve(vec![
+            response("200 OK", &[], "a"),
+            response("200 OK", &[], "b"),
+        ]);
+        let dir = temp_dir("throttle");
+        let client = Client {
+            min_interval: Duration::from_millis(300),
+            ..Client::local(&base_url, dir.clone())
+        };
+        let start = Instant::now();
+        assert_eq!(client.get("/a").unwrap(), "a");
+        assert_eq!(client.get("/b").unwrap(), "b");
+        // The time of the last request is recorded in whole milliseconds
+        assert!(start.elapsed() >= Duration::from_millis(299));
+        assert!(dir.join("last-request").exists());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn input_refuses_bad_payloads() {
+        let (base_url, server) = serve(vec![
+            response(
+                "200 OK",
+                &[],
+                "<!DOCTYPE html>\n<html><body>Oops</body></html>",
+            ),
+            response(
+                "400 Bad Request",
+                &[],
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ),
+            response("404 Not Found", &[], "Not found\n"),
+        ]);
+        let client = Client::local(&base_url, temp_dir("bad-payloads"));
+        assert_eq!(
+            client.input(2023, 1).unwrap_err().to_string(),
+            "refusing to save puzzle input (year: 2023, day: 01), the response was an HTML page"
+        );
+        assert_eq!(
+            client.input(2023, 1).unwrap_err().to_string(),
+            format!("request to `{base_url}/2023/day/1/input` failed with status 400, puzzle inputs differ by user, check that the session is set")
+        );
+        assert_eq!(
+            client.input(2023, 1).unwrap_err().to_string(),
+            format!("request to `{base_url}/2023/day/1/input` failed with status 404, Not found")
+        );
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,327 @@
+//! Verify the puzzle inputs against the checksums recorded when downloaded.
+//!
+//! The checksums are stored in `checksums.txt` in the input directory using
+//! the same format as `sha256sum`, so they can also be checked with
+//! `sha256sum --check`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use argh::FromArgs;
+use sha2::{Digest, Sha256};
+use time::OffsetDateTime;
+
+use crate::calendar::{self, FIRST_YEAR};
+use crate::config::Config;
+use crate::http;
+use crate::select::Select;
+use crate::{config, parse_args, print, warning};
+
+/// Verify the puzzle inputs or download them again.
+#[derive(Debug, FromArgs)]
+struct Opt {
+    /// the action: verify the inputs against their checksums, or refetch the
+    /// inputs that fail verification or have no checksum
+    #[argh(positional)]
+    action: Action,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Action {
+    Verify,
+    Refetch,
+}
+
+/// The checksum of each puzzle input, keyed by the path relative to the input
+/// directory.
+#[derive(Debug)]
+pub struct Checksums {
+    path: PathBuf,
+    entries: BTreeMap<String, String>,
+}
+
+/// The outcome of checking a puzzle input against its checksum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Valid,
+    /// There is no checksum recorded for the input.
+    Unrecorded,
+    /// The input has changed since it was downloaded.
+    Mismatch,
+    /// The input does not look like a puzzle input.
+    Invalid(String),
+}
+
+impl Checksums {
+    /// Loads the checksums for the input directory in the configuration.
+    pub fn load(config: &Config) -> Result<Self> {
+        Self::open(&config.input_dir().join("checksums.txt"))
+    }
+
+    fn open(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read `{}`", path.display()))
+            }
+        };
+        Self::parse(path, &text)
+    }
+
+    fn parse(path: &Path, text: &str) -> Result<Self> {
+        let mut entries = BTreeMap::new();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            // `sha256sum` marks files read in binary mode with a `*`
+            match line.split_once(' ') {
+                Some((hash, file)) if hash.len() == 64 => {
+                    let file = file.strip_prefix([' ', '*']).unwrap_or(file);
+                    entries.insert(file.to_owned(), hash.to_ascii_lowercase());
+                }
+                _ => bail!("failed to parse `{}` line {}", path.display(), i + 1),
+            }
+        }
+        Ok(Self {
+            path: path.to_owned(),
+            entries,
+        })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::create_dir_all(self.path.parent().unwrap())?;
+        fs::write(&self.path, self.to_string())
+            .with_context(|| format!("failed to write `{}`", self.path.display()))
+    }
+
+    /// Checks the text of the puzzle input against its checksum.
+    pub fn check(&self, year: u32, day: u32, text: &str) -> Check {
+        if let Err(reason) = http::check_input(text) {
+            return Check::Invalid(reason);
+        }
+        match self.entries.get(&key(year, day)) {
+            Some(hash) if *hash == sha256(text.as_bytes()) => Check::Valid,
+            Some(_) => Check::Mismatch,
+            None => Check::Unrecorded,
+        }
+    }
+
+    /// Records the checksum of the puzzle input.
+    pub fn insert(&mut self, year: u32, day: u32, text: &str) {
+        self.entries.insert(key(year, day), sha256(text.as_bytes()));
+    }
+
+    /// Returns whether a checksum is recorded for the puzzle input.
+    fn contains(&self, year: u32, day: u32) -> bool {
+        self.entries.contains_key(&key(year, day))
+    }
+}
+
+impl std::fmt::Display for Checksums {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (file, hash) in &self.entries {
+            writeln!(f, "{hash}  {file}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Records the checksum of a downloaded puzzle input.
+pub fn record(config: &Config, year: u32, day: u32, text: &str) -> Result<()> {
+    let mut checksums = Checksums::load(config)?;
+    checksums.insert(year, day, text);
+    checksums.save()
+}
+
+/// Returns the hex encoded SHA-256 digest of the data.
+pub fn sha256(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .fold(String::with_capacity(64), |mut s, b| {
+            write!(s, "{b:02x}").unwrap();
+            s
+        })
+}
+
+pub fn inputs(year: &Select, day: &Select, args: &[String]) -> Result<()> {
+    let Opt { action } = parse_args("inputs", args);
+
+    let mut checksums = Checksums::load(config::get())?;
+    let now = OffsetDateTime::now_utc();
+    // The check is `None` if there is a checksum recorded but the input is
+    // missing
+    let mut checks = Vec::new();
+    for y in (FIRST_YEAR..=calendar::latest(now).year).filter(|&y| year.contains(y)) {
+        for d in (1..=calendar::last_day(y)).filter(|&d| day.contains(d)) {
+            if calendar::release_time(y, d)? > now {
+                break;
+            }
+            let check = match fs::read_to_string(config::get().input(y, d)) {
+                Ok(text) => Some(checksums.check(y, d, &text)),
+                Err(_) if checksums.contains(y, d) => None,
+                Err(_) => continue,
+            };
+            checks.push((y, d, check));
+        }
+    }
+
+    match action {
+        Action::Verify => verify(&checks),
+        Action::Refetch => refetch(&mut checksums, &checks),
+    }
+}
+
+fn verify(checks: &[(u32, u32, Option<Check>)]) -> Result<()> {
+    let mut failures = 0;
+    let mut unrecorded = 0;
+    for (year, day, check) in checks {
+        let display = display(*year, *day);
+        match check {
+            Some(Check::Valid) => {}
+            Some(Check::Unrecorded) => unrecorded += 1,
+            Some(Check::Mismatch) => warning(
+                "Mismatch",
+                format!("puzzle input `{display}` has changed since it was downloaded"),
+            ),
+            Some(Check::Invalid(reason)) => {
+                warning("Invalid", format!("puzzle input `{display}`, {reason}"))
+            }
+            None => warning("Missing", format!("puzzle input `{display}`")),
+        }
+        failures += usize::from(!matches!(check, Some(Check::Valid | Check::Unrecorded)));
+    }
+
+    let valid = checks.len() - failures - unrecorded;
+    print(
+        "Verified",
+        format!("{valid} of {} puzzle input(s)", checks.len()),
+    );
+    if unrecorded > 0 {
+        warning(
+            "Unrecorded",
+            format!(
+                "{unrecorded} puzzle input(s) have no checksum, \
+                 run `cargo advent inputs refetch` to download and record them"
+            ),
+        );
+    }
+    if failures > 0 {
+        bail!("{failures} puzzle input(s) failed verification, run `cargo advent inputs refetch`");
+    }
+    Ok(())
+}
+
+fn refetch(checksums: &mut Checksums, checks: &[(u32, u32, Option<Check>)]) -> Result<()> {
+    let client = http::Client::new()?;
+    let mut refetched = 0;
+    for (year, day, check) in checks {
+        if *check == Some(Check::Valid) {
+            continue;
+        }
+        let (year, day) = (*year, *day);
+        let input = config::get().input(year, day);
+        let display = display(year, day);
+        let previous = fs::read_to_string(&input).ok();
+
+        let text = client.input(year, day)?;
+        fs::create_dir_all(input.parent().unwrap())?;
+        fs::write(&input, &text)?;
+        checksums.insert(year, day, &text);
+        // Save after each input in case a later request fails
+        checksums.save()?;
+        refetched += 1;
+
+        match previous {
+            Some(previous) if previous == text => {
+                print("Recorded", format!("checksum for puzzle input `{display}`"))
+            }
+            Some(_) => print("Replaced", format!("puzzle input `{display}`")),
+            None => print("Downloaded", format!("puzzle input `{display}`")),
+        }
+    }
+    print(
+        "Finished",
+        format!("refetched {refetched} of {} puzzle input(s)", checks.len()),
+    );
+    Ok(())
+}
+
+/// Returns the key for the puzzle input in the checksums file.
+fn key(year: u32, day: u32) -> String {
+    config::get()
+        .input_relative(year, day)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Returns the path to the puzzle input for display.
+fn display(year: u32, day: u32) -> String {
+    let input = config::get().input(year, day);
+    input
+        .strip_prefix(config::workspace_dir())
+        .unwrap_or(&input)
+        .display()
+        .to_string()
+}
+
+impl argh::FromArgValue for Action {
+    fn from_arg_value(value: &str) -> Result<Self, String> {
+        match value {
+            "verify" => Ok(Self::Verify),
+            "refetch" => Ok(Self::Refetch),
+            _ => Err("expected `verify` or `refetch`".into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha256_hex() {
+        assert_eq!(
+            sha256(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn checksums_roundtrip() {
+        let text = "\
+ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad  2023/02.txt
+
+BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD *2023/01.txt
+";
+        let checksums = Checksums::parse(Path::new("checksums.txt"), text).unwrap();
+        assert_eq!(
+            checksums.to_string(),
+            "\
+ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad  2023/01.txt
+ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad  2023/02.txt
+"
+        );
+        let err = Checksums::parse(Path::new("checksums.txt"), "abc  2023/01.txt\n").unwrap_err();
+        assert_eq!(err.to_string(), "failed to parse `checksums.txt` line 1");
+    }
+
+    #[test]
+    fn check_input() {
+        let mut checksums = Checksums::parse(Path::new("checksums.txt"), "").unwrap();
+        assert_eq!(checksums.check(2023, 1, "1abc2\n"), Check::Unrecorded);
+        checksums.insert(2023, 1, "1abc2\n");
+        assert_eq!(checksums.check(2023, 1, "1abc2\n"), Check::Valid);
+        // e.g. an editor stripped the trailing newline
+        assert_eq!(checksums.check(2023, 1, "1abc2"), Check::Mismatch);
+        assert_eq!(
+            checksums.check(2023, 1, "<!DOCTYPE html>"),
+            Check::Invalid("the response was an HTML page".into())
+        );
+    }
+}
@@ -0,0 +1,353 @@
+//! View a private leaderboard.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use argh::FromArgs;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::calendar;
+use crate::http::Client;
+use crate::parse_args;
+use crate::table::{Cell, Style, Table};
+
+/// Private leaderboards must not be requested more often than this.
+const MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+/// View a private leaderboard.
+#[derive(Debug, FromArgs)]
+struct Opt {
+    /// the leaderboard ID, the number at the end of the leaderboard URL
+    #[argh(positional)]
+    id: u64,
+
+    /// the output style: table, json or markdown
+    #[argh(option, default = "Output::Table")]
+    output: Output,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Output {
+    Table,
+    Json,
+    Markdown,
+}
+
+/// The leaderboard JSON from Advent of Code.
+#[derive(Debug, Deserialize)]
+struct Leaderboard {
+    members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Member {
+    id: u64,
+    name: Option<String>,
+    stars: u32,
+    local_score: u64,
+    last_star_ts: i64,
+    completion_day_level: BTreeMap<u32, BTreeMap<u32, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Star {
+    get_star_ts: i64,
+}
+
+/// A member's position on the leaderboard.
+#[derive(Debug, Serialize)]
+struct Standing {
+    rank: usize,
+    name: String,
+    local_score: u64,
+    stars: u32,
+    days: BTreeMap<u32, Times>,
+}
+
+/// The seconds taken to get each star after the puzzle was released.
+#[derive(Debug, Clone, Copy, Serialize)]
+struct Times {
+    part1: i64,
+    part2: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+struct Report<'a> {
+    year: u32,
+    id: u64,
+    standings: &'a [Standing],
+}
+
+pub fn leaderboard(year: u32, day: Option<u32>, args: &[String]) -> Result<()> {
+    let Opt { id, output } = parse_args("leaderboard", args);
+
+    let path = format!("/{year}/leaderboard/private/view/{id}.json");
+    let json = Client::new()?.get_within(&path, MAX_AGE)?;
+    let leaderboard: Leaderboard = serde_json::from_str(&json).with_context(|| {
+        format!("failed to parse leaderboard `{id}`, check that the session has access to it")
+    })?;
+    let standings = standings(year, leaderboard)?;
+
+    let now = OffsetDateTime::now_utc();
+    let released = (1..=calendar::last_day(year))
+        .take_while(|&d| calendar::release_time(year, d).is_ok_and(|t| t <= now))
+        .last()
+        .unwrap_or(0);
+    // Show the star times for the given day or the latest day with stars
+    let day = day.or_else(|| standings.iter().flat_map(|s| s.days.keys()).max().copied());
+
+    match output {
+        Output::Table => print!("{}", render(&standings, released, day, Style::stdout())),
+        Output::Markdown => print!("{}", render_markdown(&standings, released, day)),
+        Output::Json => {
+            let report = Report {
+                year,
+                id,
+                standings: &standings,
+            };
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+    }
+    Ok(())
+}
+
+/// Orders the members by local score, then stars, then who got there first.
+fn standings(year: u32, leaderboard: Leaderboard) -> Result<Vec<Standing>> {
+    let mut members: Vec<_> = leaderboard.members.into_values().collect();
+    members.sort_by(|a, b| {
+        b.local_score
+            .cmp(&a.local_score)
+            .then(b.stars.cmp(&a.stars))
+            .then(a.last_star_ts.cmp(&b.last_star_ts))
+            .then(a.id.cmp(&b.id))
+    });
+
+    let mut standings = Vec::new();
+    for (i, member) in members.into_iter().enumerate() {
+        let mut days = BTreeMap::new();
+        for (day, parts) in member.completion_day_level {
+            let release = calendar::release_time(year, day)?.unix_timestamp();
+            let Some(part1) = parts.get(&1) else {
+                continue;
+            };
+            let times = Times {
+                part1: part1.get_star_ts - release,
+                part2: parts.get(&2).map(|s| s.get_star_ts - release),
+            };
+            days.insert(day, times);
+        }
+        standings.push(Standing {
+            rank: i + 1,
+            name: member
+                .name
+                .unwrap_or_else(|| format!("(anonymous user #{})", member.id)),
+            local_score: member.local_score,
+            stars: member.stars,
+            days,
+        });
+    }
+    Ok(standings)
+}
+
+/// Renders the leaderboard like the website, with the star times for a day.
+fn render(standings: &[Standing], released: u32, day: Option<u32>, style: Style) -> String {
+    let digits = |f: fn(u32) -> char| (1..=released).map(f).collect::<String>();
+    let tens = digits(|d| match d / 10 {
+        0 => ' ',
+        n => char::from_digit(n, 10).unwrap(),
+    });
+    let ones = digits(|d| char::from_digit(d % 10, 10).unwrap());
+
+    let mut table = Table::new(&["", "", "", &tens, ""]);
+    table.bold_row(vec![
+        Cell::Plain("#".into()),
+        Cell::Plain("Score".into()),
+        Cell::Plain("⭐".into()),
+        Cell::Plain(ones),
+        Cell::Plain("Name".into()),
+    ]);
+    for s in standings {
+        let stars = (1..=released).map(|d| star(s.days.get(&d))).collect();
+        table.row(vec![
+            Cell::Dim(format!("{})", s.rank)),
+            Cell::Good(s.local_score.to_string()),
+            Cell::Plain(s.stars.to_string()),
+            Cell::Stars(stars),
+            Cell::Plain(s.name.clone()),
+        ]);
+    }
+
+    let mut out = table.render(style);
+    if let Some(day) = day {
+        out.push('\n');
+        out.push_str(&day_table(standings, day).render(style));
+    }
+    out
+}
+
+/// Renders the leaderboard as Markdown with a column for each day.
+fn render_markdown(standings: &[Standing], released: u32, day: Option<u32>) -> String {
+    let days: Vec<_> = (1..=released).map(|d| d.to_string()).collect();
+    let header: Vec<_> = ["#", "Name", "Score", "⭐"]
+        .into_iter()
+        .chain(days.iter().map(String::as_str))
+        .collect();
+    let mut table = Table::new(&header);
+    for s in standings {
+        let mut row = vec![
+            Cell::Plain(s.rank.to_string()),
+            Cell::Plain(s.name.clone()),
+            Cell::Plain(s.local_score.to_string()),
+            Cell::Plain(s.stars.to_string()),
+        ];
+        row.extend((1..=released).map(|d| match s.days.get(&d) {
+            None => Cell::Plain(String::new()),
+            times => Cell::Stars(star(times).to_string()),
+        }));
+        table.row(row);
+    }
+
+    let mut out = table.markdown();
+    if let Some(day) = day {
+        out.push('\n');
+        out.push_str(&day_table(standings, day).markdown());
+    }
+    out
+}
+
+/// Returns a table of the time taken to get each star for the day, along
+/// with the time between the stars.
+fn day_table(standings: &[Standing], day: u32) -> Table {
+    let mut times: Vec<_> = standings
+        .iter()
+        .filter_map(|s| Some((s, *s.days.get(&day)?)))
+        .collect();
+    times.sort_by_key(|(_, t)| (t.part2.is_none(), t.part2, t.part1));
+
+    let mut table = Table::new(&[&format!("Day {day:02}"), "Part 1", "Part 2", "Delta"]);
+    for (s, t) in times {
+        let (part2, delta) = match t.part2 {
+            Some(part2) => (
+                Cell::Plain(human_duration(part2)),
+                Cell::Good(format!("+{}", human_duration(part2 - t.part1))),
+            ),
+            None => (Cell::Dim("-".into()), Cell::Dim("-".into())),
+        };
+        table.row(vec![
+            Cell::Plain(s.name.clone()),
+            Cell::Plain(human_duration(t.part1)),
+            part2,
+            delta,
+        ]);
+    }
+    table
+}
+
+fn star(times: Option<&Times>) -> char {
+    match times {
+        Some(Times { part2: Some(_), .. }) => '★',
+        Some(_) => '☆',
+        None => '·',
+    }
+}
+
+/// Formats a number of seconds like the website, e.g. `01:02:03`.
+fn human_duration(secs: i64) -> String {
+    let secs = secs.max(0);
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+impl argh::FromArgValue for Output {
+    fn from_arg_value(value: &str) -> Result<Self, String> {
+        match value {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            "markdown" => Ok(Self::Markdown),
+            _ => Err("expected `table`, `json` or `markdown`".into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEADERBOARD: &str = include_str!("../fixtures/leaderboard.json");
+
+    fn fixture() -> Vec<Standing> {
+        standings(2023, serde_json::from_str(LEADERBOARD).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn standings_order() {
+        let standings = fixture();
+        let names: Vec<_> = standings.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["Alice", "Ross", "(anonymous user #1003)", "Bob"]);
+        let alice = &standings[0];
+        assert_eq!(alice.days[&1].part1, 300);
+        assert_eq!(alice.days[&1].part2, Some(1500));
+        assert_eq!(standings[1].days[&3].part2, None);
+    }
+
+    #[test]
+    fn render_table() {
+        assert_eq!(
+            render(&fixture(), 12, Some(1), Style::Plain),
+            "                        111\n\
+#   Score  ⭐  123456789012  Name
+1)  23     6   ★★★·········  Alice
+2)  16     5   ★★☆·········  Ross
+3)  2      1   ☆···········  (anonymous user #1003)
+4)  0      0   ············  Bob
+
+Day 01                  Part 1    Part 2    Delta
+Ross                    00:10:00  00:15:00  +00:05:00
+Alice                   00:05:00  00:25:00  +00:20:00
+(anonymous user #1003)  48:00:00  -         -
+"
+        );
+    }
+
+    #[test]
+    fn render_table_markdown() {
+        assert_eq!(
+            render_markdown(&fixture(), 3, Some(3)),
+            "\
+| # | Name | Score | ⭐ | 1 | 2 | 3 |
+| --- | --- | --- | --- | --- | --- | --- |
+| 1 | Alice | 23 | 6 | ★ | ★ | ★ |
+| 2 | Ross | 16 | 5 | ★ | ★ | ☆ |
+| 3 | (anonymous user #1003) | 2 | 1 | ☆ |  |  |
+| 4 | Bob | 0 | 0 |  |  |  |
+
+| Day 03 | Part 1 | Part 2 | Delta |
+| --- | --- | --- | --- |
+| Alice | 00:16:40 | 00:21:40 | +00:05:00 |
+| Ross | 02:00:00 | - | - |
+"
+        );
+    }
+
+    #[test]
+    fn report_json() {
+        let standings = fixture();
+        let report = Report {
+            year: 2023,
+            id: 1001,
+            standings: &standings[3..],
+        };
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"year":2023,"id":1001,"standings":[{"rank":4,"name":"Bob","local_score":0,"stars":0,"days":{}}]}"#
+        );
+    }
+
+    #[test]
+    fn human_duration_format() {
+        assert_eq!(human_duration(0), "00:00:00");
+        assert_eq!(human_duration(3723), "01:02:03");
+        assert_eq!(human_duration(90_000), "25:00:00");
+    }
+}
@@ -1,3 +1,26 @@
+mod batch;
+mod calendar;
+mod compare;
+mod completions;
+mod config;
+mod crosscheck;
+mod doctor;
+mod examples;
+mod git;
+mod history;
+mod http;
+mod inputs;
+mod leaderboard;
+mod manifest;
+mod readme;
+mod select;
+mod status;
+mod submit;
+mod table;
+mod template;
+mod wait;
+mod watch;
+
 use std::env;
 use std::fmt::Display;
 use std::fs;
@@ -6,24 +29,39 @@ use std::io::IsTerminal;
 use std::path::PathBuf;
 use std::process;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Result};
 use argh::FromArgs;
-use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
 use yansi::Paint;
 
+use crate::batch::Puzzle;
+use crate::config::Config;
+use crate::inputs::{Check, Checksums};
+use crate::manifest::{Binary, Manifest};
+use crate::select::Select;
+
 /// 🎄 Festive Advent of Code solution management
 #[derive(Debug, FromArgs)]
-#[argh(example = "cargo advent -y 2021 -d 17 run")]
+#[argh(
+    example = "cargo advent run",
+    example = "cargo advent -y 2021 -d 17 run",
+    example = "cargo advent -y 2020,2021 -d 1..=10 bench"
+)]
 struct Opt {
-    /// the puzzle year
+    /// the puzzle year, multiple years e.g. `2021,2022` or `all`, or relative
+    /// to the current year e.g. `prev`, defaults to the current year
     #[argh(option, short = 'y')]
-    year: u32,
+    year: Option<Select>,
 
-    /// the puzzle day
+    /// the puzzle day, multiple days e.g. `1..=10`, `1,3,5` or `all`, or
+    /// relative to the current day e.g. `+1` or `prev`, defaults to the
+    /// current day
     #[argh(option, short = 'd')]
-    day: u32,
+    day: Option<Select>,
 
-    /// the subcommand: bench, new, open, or a Cargo subcommand
+    /// the subcommand: bench, compare, completions, config, crosscheck,
+    /// doctor, examples, history, inputs, leaderboard, new, open, readme,
+    /// remove, rename, status, submit, wait, watch, or a Cargo subcommand
     #[argh(positional)]
     command: Command,
 
@@ -34,8 +72,24 @@ struct Opt {
 #[derive(Debug)]
 enum Command {
     Bench,
+    Compare,
+    Completions,
+    Config,
+    Crosscheck,
+    Doctor,
+    Examples,
+    History,
+    Inputs,
+    Leaderboard,
     New,
     Open,
+    Readme,
+    Remove,
+    Rename,
+    Status,
+    Submit,
+    Wait,
+    Watch,
     Cargo(String),
 }
 
@@ -44,9 +98,28 @@ impl argh::FromArgValue for Command {
         match value {
             "new" => Ok(Self::New),
             "open" => Ok(Self::Open),
+            "readme" => Ok(Self::Readme),
+            "remove" => Ok(Self::Remove),
+            "rename" => Ok(Self::Rename),
             "bench" => Ok(Self::Bench),
+            "compare" => Ok(Self::Compare),
+            "completions" => Ok(Self::Completions),
+            "config" => Ok(Self::Config),
+            "crosscheck" => Ok(Self::Crosscheck),
+            "doctor" => Ok(Self::Doctor),
+            "examples" => Ok(Self::Examples),
+            "history" => Ok(Self::History),
+            "inputs" => Ok(Self::Inputs),
+            "leaderboard" => Ok(Self::Leaderboard),
+            "status" => Ok(Self::Status),
+            "submit" => Ok(Self::Submit),
+            "wait" => Ok(Self::Wait),
+            "watch" => Ok(Self::Watch),
             "check" | "build" | "test" | "run" | "clippy" => Ok(Self::Cargo(value.into())),
-            _ => Err("expected one of: bench, new, open, or a Cargo subcommand".into()),
+            _ => Err(
+                "expected one of: bench, compare, completions, config, crosscheck, doctor, examples, history, inputs, leaderboard, new, open, readme, remove, rename, status, submit, wait, watch, or a Cargo subcommand"
+                    .into(),
+            ),
         }
     }
 }
@@ -57,90 +130,203 @@ fn main() -> Result<()> {
         day,
         command,
         args,
-    } = argh::from_env();
+    } = parse_opt();
+
+    // The doctor reports a config that fails to load instead of exiting
+    if let Command::Doctor = command {
+        return doctor::doctor(&args);
+    }
+    config::init()?;
+    if let Command::Config = command {
+        return show_config(&args);
+    }
+    if let Command::Completions = command {
+        return completions::completions(&args);
+    }
+    if let Command::Readme = command {
+        return readme::readme(&args);
+    }
+
+    // The current puzzle is the latest released puzzle unless configured, or
+    // the next puzzle when waiting for it to be released
+    let config = config::get();
+    let now = OffsetDateTime::now_utc();
+    let (current, source) = match (&config.current, &command) {
+        (Some(current), _) => (config.current().unwrap(), format!("`{}`", current.source)),
+        (None, Command::Wait) => (calendar::next(now), String::from("the calendar")),
+        (None, _) => (calendar::latest(now), String::from("the calendar")),
+    };
+    // The leaderboard only needs a year and the status defaults to everything
+    let (year, day) = match command {
+        Command::Leaderboard => (year, day.or(Some(Select::All))),
+        Command::Inputs | Command::Status => {
+            (year.or(Some(Select::All)), day.or(Some(Select::All)))
+        }
+        _ => (year, day),
+    };
+    let (year, day, implicit) = select::resolve(year, day, current).map_err(anyhow::Error::msg)?;
+    if implicit {
+        if let (Some(year), Some(day)) = (year.single(), day.single()) {
+            print(
+                "Selected",
+                format!("puzzle (year: {year:04}, day: {day:02}) using {source}"),
+            );
+        }
+    }
+
+    if let Command::Status = command {
+        return status::status(&year, &day, &args);
+    }
+    if let Command::Inputs = command {
+        return inputs::inputs(&year, &day, &args);
+    }
+    if let Command::Leaderboard = command {
+        let Some(year) = year.single() else {
+            bail!("the leaderboard subcommand requires a single year");
+        };
+        return leaderboard::leaderboard(year, day.single(), &args);
+    }
+
+    let (Some(year), Some(day)) = (year.single(), day.single()) else {
+        let puzzles = select_puzzles(&year, &day)?;
+        return match command {
+            Command::Bench => batch::bench(&puzzles, &args),
+            Command::Crosscheck => crosscheck::crosscheck(&puzzles, &args),
+            Command::Cargo(cmd) if cmd == "run" => batch::run(&puzzles, &args),
+            Command::Cargo(cmd) if cmd == "test" => batch::test(&puzzles, &args),
+            Command::Cargo(cmd) => cargo_many(cmd, &puzzles, &args),
+            _ => bail!("this subcommand requires a single year and day"),
+        };
+    };
 
     match command {
         Command::Bench => bench(year, day, &args),
-        Command::New => new(year, day),
+        Command::Compare => compare::compare(year, day, &args),
+        Command::Crosscheck => crosscheck::crosscheck(&[Puzzle { year, day }], &args),
+        Command::Examples => examples::examples(year, day, &args),
+        Command::History => history::history(year, day, &args),
+        Command::New => new(year, day, &args),
         Command::Open => open(year, day, &args),
+        Command::Remove => remove(year, day),
+        Command::Rename => rename(year, day, &args),
+        Command::Submit => submit::submit(year, day, &args),
+        Command::Wait => wait::wait(year, day, &args),
+        Command::Watch => watch::watch(year, day, &args),
         Command::Cargo(cmd) => cargo(cmd, year, day, &args),
+        Command::Completions
+        | Command::Config
+        | Command::Doctor
+        | Command::Inputs
+        | Command::Leaderboard
+        | Command::Readme
+        | Command::Status => {
+            unreachable!()
+        }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
-struct Binary {
-    name: String,
-    path: PathBuf,
+fn show_config(args: &[String]) -> Result<()> {
+    match args {
+        [] => bail!("expected a config subcommand: show"),
+        [cmd] if cmd == "show" => {
+            print!("{}", config::get().show());
+            Ok(())
+        }
+        [cmd, ..] => bail!("unknown config subcommand `{cmd}`, expected: show"),
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
-struct Binaries {
-    bin: Vec<Binary>,
+/// Returns the puzzles with solution binaries.
+fn puzzles() -> Result<Vec<Puzzle>> {
+    // Skip any binaries that are not puzzle solutions
+    let mut puzzles: Vec<_> = Manifest::workspace()?
+        .bins()
+        .iter()
+        .filter_map(|bin| batch::parse_bin_name(&bin.name))
+        .collect();
+    puzzles.sort();
+    Ok(puzzles)
 }
 
-fn download(url: &str) -> Result<String> {
-    let mut buf = Vec::new();
-    let mut easy = curl::easy::Easy::new();
-    easy.fail_on_error(true)?;
-    easy.follow_location(true)?;
-    easy.useragent("github.com/rossmacarthur/advent by ross@macarthur.io")?;
-    easy.cookie(&format!(
-        "session={}",
-        env::var("ADVENT_SESSION").context("`ADVENT_SESSION` must be set")?
-    ))?;
-    easy.url(url)?;
-    {
-        let mut transfer = easy.transfer();
-        transfer.write_function(|data| {
-            buf.extend_from_slice(data);
-            Ok(data.len())
-        })?;
-        transfer.perform()?;
+/// Returns the puzzles with solution binaries matching the given selection.
+fn select_puzzles(year: &Select, day: &Select) -> Result<Vec<Puzzle>> {
+    let puzzles: Vec<_> = puzzles()?
+        .into_iter()
+        .filter(|p| year.contains(p.year) && day.contains(p.day))
+        .collect();
+    if puzzles.is_empty() {
+        bail!("no solutions match the given year and day");
     }
-    Ok(String::from_utf8(buf)?)
+    Ok(puzzles)
+}
+
+/// Add a new solution from a template.
+#[derive(Debug, FromArgs)]
+struct NewOpt {
+    /// the template name or path, names are looked up in the workspace
+    /// `templates/` directory and then the built-in templates: default,
+    /// device, grid and intcode
+    #[argh(option, default = "String::from(\"default\")")]
+    template: String,
 }
 
-fn new(year: u32, day: u32) -> Result<()> {
+fn new(year: u32, day: u32, args: &[String]) -> Result<()> {
+    let NewOpt { template } = parse_args("new", args);
+    scaffold(year, day, &template, true)
+}
+
+/// Adds the solution from the template along with the input and optionally
+/// the example inputs.
+fn scaffold(year: u32, day: u32, template: &str, examples: bool) -> Result<()> {
     let bin_name = format!("{year:04}{day:02}");
 
-    let workspace_dir = PathBuf::from(env!("CARGO_WORKSPACE_DIR"));
-    let manifest_path = workspace_dir.join("Cargo.toml");
+    let workspace_dir = config::workspace_dir();
     let bin = workspace_dir.join(format!("{year:04}/{day:02}.rs"));
 
+    // The puzzle page has the title and examples once released
+    let page = match calendar::is_released(year, day)? {
+        true => Some(examples::page(year, day)?),
+        false => None,
+    };
+
     // Add {year}/{day}.rs file
-    const TEMPLATE: &str = include_str!("template.rs");
     let bin_display = bin.strip_prefix(&workspace_dir).unwrap_or(&bin).display();
     if bin.exists() {
         print("Checked", format!("binary source `{bin_display}`"));
     } else {
-        let rendered = TEMPLATE
-            .replace("{ year }", &format!("{year:04}"))
-            .replace("{ day }", &format!("{day:02}"));
+        let (template, source) = template::load(&workspace_dir, template)?;
+        let vars = template::Vars {
+            year,
+            day,
+            title: page.as_deref().and_then(examples::title),
+            base_url: config::get().base_url.value.clone(),
+        };
+        let rendered = template::render(&template, &vars);
         fs::create_dir_all(bin.parent().unwrap())?;
-        fs::write(&bin, rendered)?;
+        fs::write(&bin, &rendered)?;
         print(
             "Created",
-            format!("binary source `{bin_display}` from template"),
+            format!("binary source `{bin_display}` from {source}"),
         );
+        for module in template::modules(&rendered) {
+            let path = bin.with_file_name(format!("{module}.rs"));
+            if !path.exists() {
+                warning(
+                    "Missing",
+                    format!("module `{year:04}/{module}.rs` used by the template"),
+                );
+            }
+        }
     }
 
     // Update Cargo.toml
-    let manifest = fs::read_to_string(&manifest_path)?;
-    let index = manifest.find("[[bin]]").unwrap();
-    let (main, binaries) = manifest.split_at(index);
-    let mut bins: Binaries = toml::from_str(binaries)?;
-    let to_add = Binary {
+    let mut manifest = Manifest::workspace()?;
+    let added = manifest.add_bin(&Binary {
         name: bin_name.clone(),
         path: bin.strip_prefix(&workspace_dir)?.to_owned(),
-    };
-    let added = !bins.bin.contains(&to_add);
-    bins.bin.push(to_add);
-    bins.bin.sort();
-    bins.bin.dedup();
-    let binaries = toml::to_string(&bins)?;
-    fs::write(&manifest_path, main.to_owned() + &binaries)?;
-
+    })?;
     if added {
+        manifest.save()?;
         print(
             "Updated",
             format!("Cargo manifest with `{bin_name}` binary"),
@@ -153,26 +339,130 @@ fn new(year: u32, day: u32) -> Result<()> {
     }
 
     check_input(year, day)?;
+    if let (Some(page), true) = (page, examples) {
+        examples::save(year, day, &page)?;
+    }
+
+    Ok(())
+}
 
+fn remove(year: u32, day: u32) -> Result<()> {
+    let bin_name = format!("{year:04}{day:02}");
+    let workspace_dir = config::workspace_dir();
+
+    let mut manifest = Manifest::workspace()?;
+    let path = match manifest.remove_bin(&bin_name)? {
+        Some(bin) => {
+            manifest.save()?;
+            print(
+                "Updated",
+                format!("Cargo manifest without `{bin_name}` binary"),
+            );
+            bin.path
+        }
+        None => PathBuf::from(format!("{year:04}/{day:02}.rs")),
+    };
+
+    let bin = workspace_dir.join(&path);
+    if bin.exists() {
+        fs::remove_file(&bin)?;
+        print("Removed", format!("binary source `{}`", path.display()));
+    } else if !manifest.bins().is_empty() {
+        warning("Missing", format!("binary source `{}`", path.display()));
+    }
+    Ok(())
+}
+
+/// Move a solution to another puzzle.
+#[derive(Debug, FromArgs)]
+struct RenameOpt {
+    /// the puzzle to move the solution to, e.g. `2023/05`
+    #[argh(positional)]
+    to: String,
+}
+
+fn rename(year: u32, day: u32, args: &[String]) -> Result<()> {
+    let RenameOpt { to } = parse_args("rename", args);
+    let Some((to_year, to_day)) = to
+        .split_once('/')
+        .and_then(|(y, d)| Some((y.parse::<u32>().ok()?, d.parse::<u32>().ok()?)))
+        .filter(|&(y, d)| y >= calendar::FIRST_YEAR && (1..=25).contains(&d))
+    else {
+        bail!("invalid puzzle `{to}`, expected a year and day, e.g. `2023/05`");
+    };
+
+    let bin_name = format!("{year:04}{day:02}");
+    let workspace_dir = config::workspace_dir();
+    let to_bin = Binary {
+        name: format!("{to_year:04}{to_day:02}"),
+        path: PathBuf::from(format!("{to_year:04}/{to_day:02}.rs")),
+    };
+    let to_path = workspace_dir.join(&to_bin.path);
+    if to_path.exists() {
+        bail!("binary source `{}` already exists", to_bin.path.display());
+    }
+
+    let mut manifest = Manifest::workspace()?;
+    let Some(from_bin) = manifest.rename_bin(&bin_name, &to_bin)? else {
+        bail!("Cargo manifest does not contain `{bin_name}` binary");
+    };
+
+    // Move the source, pointing it at the input for the new puzzle
+    let from_path = workspace_dir.join(&from_bin.path);
+    let source = fs::read_to_string(&from_path)?.replace(
+        &format!("include_input!({year:04} / {day:02})"),
+        &format!("include_input!({to_year:04} / {to_day:02})"),
+    );
+    fs::create_dir_all(to_path.parent().unwrap())?;
+    fs::write(&to_path, &source)?;
+    fs::remove_file(&from_path)?;
+    print(
+        "Renamed",
+        format!(
+            "binary source `{}` to `{}`",
+            from_bin.path.display(),
+            to_bin.path.display()
+        ),
+    );
+    for module in template::modules(&source) {
+        if !to_path.with_file_name(format!("{module}.rs")).exists() {
+            warning(
+                "Missing",
+                format!("module `{to_year:04}/{module}.rs` used by the solution"),
+            );
+        }
+    }
+
+    manifest.save()?;
+    print(
+        "Updated",
+        format!(
+            "Cargo manifest with `{}` binary instead of `{bin_name}`",
+            to_bin.name
+        ),
+    );
     Ok(())
 }
 
 fn open(year: u32, day: u32, args: &[String]) -> Result<()> {
-    let url = format!("https://adventofcode.com/{year}/day/{day}");
-    let browser = args.get(0).map(|s| s.as_str()).unwrap_or("firefox");
+    let url = format!("{}/{year}/day/{day}", config::get().base_url.value);
+    let browser = match args.get(0) {
+        Some(browser) => browser,
+        None => &config::get().browser.value,
+    };
     open::with(url, browser)?;
     Ok(())
 }
 
 fn check_input(year: u32, day: u32) -> Result<()> {
+    check_profile_input(config::get(), year, day)
+}
+
+/// Checks the puzzle input for the profile in the configuration, downloading
+/// it if it is missing or invalid.
+fn check_profile_input(config: &Config, year: u32, day: u32) -> Result<()> {
     // Only try download if the puzzle has actually been released
-    let now = time::OffsetDateTime::now_utc();
-    let puzzle = time::PrimitiveDateTime::new(
-        time::Date::from_calendar_date(year as i32, time::Month::December, day as u8)?,
-        time::Time::from_hms(5, 0, 0)?,
-    )
-    .assume_utc();
-    if now < puzzle {
+    if !calendar::is_released(year, day)? {
         warning(
             "Unavailable",
             format!("puzzle input (year: {year:04}, day: {day:02})"),
@@ -181,8 +471,8 @@ fn check_input(year: u32, day: u32) -> Result<()> {
     }
 
     // Calculate input file path
-    let workspace_dir = PathBuf::from(env!("CARGO_WORKSPACE_DIR"));
-    let input = workspace_dir.join(format!("input/{year:04}/{day:02}.txt"));
+    let workspace_dir = config::workspace_dir();
+    let input = config.input(year, day);
 
     // Create directory if not exists
     fs::create_dir_all(input.parent().unwrap())?;
@@ -192,45 +482,181 @@ fn check_input(year: u32, day: u32) -> Result<()> {
         .unwrap_or(&input)
         .display();
 
-    if !input.exists() {
-        let url = format!("https://adventofcode.com/{year}/day/{day}/input");
-        let text = download(&url)?;
-        fs::write(&input, text)?;
-        print("Downloaded", format!("puzzle input `{input_display}`"));
-    } else {
-        print("Verified", format!("puzzle input `{input_display}`"));
+    // Replace the input if it was saved before inputs were checked
+    if let Ok(text) = fs::read_to_string(&input) {
+        match Checksums::load(config)?.check(year, day, &text) {
+            Check::Valid => {
+                print("Verified", format!("puzzle input `{input_display}`"));
+                return Ok(());
+            }
+            // The input passed the checks for an error page but was saved
+            // before checksums were recorded, so it is kept but not trusted
+            Check::Unrecorded => {
+                warning(
+                    "Unrecorded",
+                    format!(
+                        "puzzle input `{input_display}` has no checksum, \
+                         run `cargo advent -y {year} -d {day} inputs refetch` to record it"
+                    ),
+                );
+                return Ok(());
+            }
+            Check::Mismatch => {
+                warning(
+                    "Mismatch",
+                    format!(
+                        "puzzle input `{input_display}` has changed since it was downloaded, \
+                         run `cargo advent -y {year} -d {day} inputs refetch` to replace it"
+                    ),
+                );
+                return Ok(());
+            }
+            Check::Invalid(reason) => warning(
+                "Invalid",
+                format!("puzzle input `{input_display}`, {reason}"),
+            ),
+        }
     }
 
+    let text = http::Client::with_config(config)?.input(year, day)?;
+    fs::write(&input, &text)?;
+    inputs::record(config, year, day, &text)?;
+    print("Downloaded", format!("puzzle input `{input_display}`"));
+
     Ok(())
 }
 
 fn bench(year: u32, day: u32, args: &[String]) -> Result<()> {
     check_input(year, day)?;
 
-    let bin_name = format!("{year:04}{day:02}");
-
-    let (cargo_args, bin_args) = match args.iter().position(|a| a == "--") {
-        Some(i) => (&args[..i], &args[i + 1..]),
-        None => (args, &[][..]),
-    };
-
-    let status = process::Command::new(env!("CARGO"))
-        .args(["run", "--release", "--bin", &bin_name])
-        .args(cargo_args)
-        .args(["--", "--bench"])
-        .args(bin_args)
+    // The binary also writes the JSON summary here to be recorded
+    let summary = env::temp_dir().join(format!("advent-summary-{}.json", process::id()));
+    let status = solution_command("bench", year, day, args)
+        .env("ADVENT_SUMMARY", &summary)
         .status()?;
+    if let Ok(json) = fs::read(&summary) {
+        fs::remove_file(&summary)?;
+        if let (true, Ok(batch::Summary::Bench(benches))) =
+            (status.success(), serde_json::from_slice(&json))
+        {
+            history::record(&[(Puzzle { year, day }, &benches)])?;
+        }
+    }
 
     process::exit(status.code().unwrap())
 }
 
 fn cargo(cmd: String, year: u32, day: u32, args: &[String]) -> Result<()> {
     check_input(year, day)?;
+    let status = solution_command(&cmd, year, day, args).status()?;
+    process::exit(status.code().unwrap())
+}
 
+/// Returns the Cargo command to bench the solution or to run the Cargo
+/// subcommand for it.
+fn solution_command(cmd: &str, year: u32, day: u32, args: &[String]) -> process::Command {
     let bin_name = format!("{year:04}{day:02}");
 
-    let status = process::Command::new(env!("CARGO"))
-        .args([&cmd, "--release", "--bin", &bin_name])
+    let mut command = cargo_command();
+    match cmd {
+        "bench" => {
+            let (cargo_args, bin_args) = split_args(args);
+            command
+                .args(["run", "--release", "--features", "json", "--bin", &bin_name])
+                .args(cargo_args)
+                .args(["--", "--bench"])
+                .args(bin_args);
+        }
+        "run" => {
+            let (cargo_args, bin_args) = split_args(args);
+            command
+                .args([cmd, "--release", "--bin", &bin_name])
+                .args(cargo_args)
+                .arg("--")
+                .args(bin_args);
+        }
+        _ => {
+            command
+                .args([cmd, "--release", "--bin", &bin_name])
+                .args(&config::get().cargo_args.value)
+                .args(args);
+        }
+    }
+    command
+}
+
+/// Parses the arguments for a subcommand, exiting on error or `--help`.
+fn parse_args<T: FromArgs>(command: &str, args: &[String]) -> T {
+    from_args(&format!("cargo advent {command}"), args)
+}
+
+/// Parses the command line, passing the arguments after the subcommand through
+/// untouched so that the subcommand options are not rejected.
+fn parse_opt() -> Opt {
+    let args: Vec<_> = env::args().collect();
+    // Find the subcommand by skipping over the options and their values
+    let mut i = 1;
+    while let Some(arg) = args.get(i) {
+        match arg.as_str() {
+            "-y" | "--year" | "-d" | "--day" => i += 2,
+            arg if arg.starts_with('-') => i += 1,
+            _ => break,
+        }
+    }
+    let split = (i + 1).min(args.len());
+    let mut opt: Opt = from_args("cargo advent", &args[1..split]);
+    opt.args = args[split..].to_vec();
+    opt
+}
+
+fn from_args<T: FromArgs>(name: &str, args: &[String]) -> T {
+    let args: Vec<_> = args.iter().map(String::as_str).collect();
+    match T::from_args(&[name], &args) {
+        Ok(opt) => opt,
+        Err(early) => {
+            match early.status {
+                Ok(()) => println!("{}", early.output),
+                Err(()) => eprintln!("{}", early.output),
+            }
+            process::exit(early.status.map_or(1, |()| 0));
+        }
+    }
+}
+
+/// Returns a Cargo command with the input locations set for the solutions.
+fn cargo_command() -> process::Command {
+    let mut command = process::Command::new(env!("CARGO"));
+    command.envs(config::get().input_env());
+    command
+}
+
+/// Splits the arguments into those for Cargo and those for the binary, adding
+/// the configured default arguments.
+fn split_args(args: &[String]) -> (Vec<String>, Vec<String>) {
+    let config = config::get();
+    let (cargo_args, bin_args) = match args.iter().position(|a| a == "--") {
+        Some(i) => (&args[..i], &args[i + 1..]),
+        None => (args, &[][..]),
+    };
+    let cargo_args = [&config.cargo_args.value[..], cargo_args].concat();
+    let mut defaults = config.bin_args.value.clone();
+    if let Some(output) = &config.output {
+        if !bin_args.iter().any(|a| a.starts_with("--output")) {
+            defaults.extend(["--output".to_owned(), output.value.clone()]);
+        }
+    }
+    (cargo_args, [&defaults[..], bin_args].concat())
+}
+
+fn cargo_many(cmd: String, puzzles: &[Puzzle], args: &[String]) -> Result<()> {
+    let status = cargo_command()
+        .args([&cmd, "--release"])
+        .args(
+            puzzles
+                .iter()
+                .flat_map(|p| ["--bin".to_owned(), p.bin_name()]),
+        )
+        .args(&config::get().cargo_args.value)
         .args(args)
         .status()?;
 
@@ -252,3 +678,11 @@ fn warning(header: &str, message: impl Display) {
         println!("{:>12} {}", header, message);
     }
 }
+
+fn failure(header: &str, message: impl Display) {
+    if io::stdout().is_terminal() {
+        println!("{:>12} {}", Paint::new(&header).bold().red(), message);
+    } else {
+        println!("{:>12} {}", header, message);
+    }
+}
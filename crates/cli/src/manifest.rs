@@ -0,0 +1,331 @@
+//! Edit the binaries in the workspace Cargo manifest.
+//!
+//! Edits preserve the formatting and comments in the manifest, as well as any
+//! other keys on the binaries and any other sections.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use toml_edit::{value, ArrayOfTables, Document, Item, Table, TableLike, Value};
+
+use crate::config;
+
+/// A binary target in the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Binary {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+#[derive(Debug)]
+pub struct Manifest {
+    path: PathBuf,
+    doc: Document,
+}
+
+impl Manifest {
+    /// Opens the workspace manifest.
+    pub fn workspace() -> Result<Self> {
+        Self::open(&config::workspace_dir().join("Cargo.toml"))
+    }
+
+    pub fn open(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("failed to read `{}`", path.display()))?;
+        Self::parse(path, &text)
+    }
+
+    fn parse(path: &Path, text: &str) -> Result<Self> {
+        let doc = text
+            .parse()
+            .with_context(|| format!("failed to parse `{}`", path.display()))?;
+        Ok(Self {
+            path: path.to_owned(),
+            doc,
+        })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::write(&self.path, self.doc.to_string())
+            .with_context(|| format!("failed to write `{}`", self.path.display()))
+    }
+
+    /// Returns the binaries in the order they appear in the manifest.
+    pub fn bins(&self) -> Vec<Binary> {
+        let tables: Vec<&dyn TableLike> = match self.doc.get("bin") {
+            Some(Item::ArrayOfTables(tables)) => tables.iter().map(|t| t as _).collect(),
+            Some(Item::Value(Value::Array(array))) => array
+                .iter()
+                .filter_map(|v| v.as_inline_table())
+                .map(|t| t as _)
+                .collect(),
+            _ => Vec::new(),
+        };
+        tables.into_iter().filter_map(binary).collect()
+    }
+
+    /// Adds the binary before the first binary with a greater name, returning
+    /// whether it was added.
+    pub fn add_bin(&mut self, bin: &Binary) -> Result<bool> {
+        let bins = self.bins_mut()?;
+        if bins.iter().any(|t| get_str(t, "name") == Some(&bin.name)) {
+            return Ok(false);
+        }
+
+        let mut table = Table::new();
+        table.insert("name", value(bin.name.as_str()));
+        table.insert("path", value(path_str(&bin.path)));
+
+        // Tables are rendered in document position order, so the new table
+        // takes the position of its neighbour to be rendered next to it.
+        let mut tables: Vec<_> = bins.iter().cloned().collect();
+        let index = tables
+            .iter()
+            .position(|t| get_str(t, "name").is_some_and(|name| name > bin.name.as_str()))
+            .unwrap_or(tables.len());
+        let neighbour = tables.get(index).or_else(|| tables.last());
+        if let Some(position) = neighbour.and_then(Table::position) {
+            table.set_position(position);
+        }
+        tables.insert(index, table);
+
+        bins.clear();
+        for table in tables {
+            bins.push(table);
+        }
+        Ok(true)
+    }
+
+    /// Removes the binary with the given name, returning it if it was found.
+    pub fn remove_bin(&mut self, name: &str) -> Result<Option<Binary>> {
+        let bins = self.bins_mut()?;
+        let Some(index) = bins.iter().position(|t| get_str(t, "name") == Some(name)) else {
+            return Ok(None);
+        };
+        let bin = bins.get(index).and_then(|t| binary(t));
+        bins.remove(index);
+        Ok(bin)
+    }
+
+    /// Renames the binary with the given name, keeping its position and any
+    /// other keys, returning the binary if it was found.
+    pub fn rename_bin(&mut self, name: &str, to: &Binary) -> Result<Option<Binary>> {
+        let bins = self.bins_mut()?;
+        if bins.iter().any(|t| get_str(t, "name") == Some(&to.name)) {
+            bail!("Cargo manifest already contains `{}` binary", to.name);
+        }
+        let Some(table) = bins.iter_mut().find(|t| get_str(*t, "name") == Some(name)) else {
+            return Ok(None);
+        };
+        let bin = binary(table);
+        set_str(table, "name", &to.name);
+        set_str(table, "path", &path_str(&to.path));
+        Ok(bin)
+    }
+
+    fn bins_mut(&mut self) -> Result<&mut ArrayOfTables> {
+        self.doc
+            .entry("bin")
+            .or_insert_with(|| Item::ArrayOfTables(ArrayOfTables::new()))
+            .as_array_of_tables_mut()
+            .context("failed to edit Cargo manifest, expected `[[bin]]` tables")
+    }
+}
+
+fn binary(table: &dyn TableLike) -> Option<Binary> {
+    let name = get_str(table, "name")?;
+    let path = match get_str(table, "path") {
+        Some(path) => PathBuf::from(path),
+        None => PathBuf::from(format!("src/bin/{name}.rs")),
+    };
+    Some(Binary {
+        name: name.to_owned(),
+        path,
+    })
+}
+
+fn get_str<'a>(table: &'a dyn TableLike, key: &str) -> Option<&'a str> {
+    table.get(key)?.as_str()
+}
+
+/// Sets a string value, keeping the whitespace and comments around it.
+fn set_str(table: &mut Table, key: &str, value: &str) {
+    match table.get_mut(key).and_then(Item::as_value_mut) {
+        Some(v) => {
+            let decor = v.decor().clone();
+            *v = value.into();
+            *v.decor_mut() = decor;
+        }
+        None => {
+            table.insert(key, toml_edit::value(value));
+        }
+    }
+}
+
+fn path_str(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(text: &str) -> Manifest {
+        Manifest::parse(Path::new("Cargo.toml"), text).unwrap()
+    }
+
+    fn bin(name: &str, path: &str) -> Binary {
+        Binary {
+            name: name.into(),
+            path: path.into(),
+        }
+    }
+
+    const MANIFEST: &str = r#"[package]
+name = "advent-bins"
+
+# Solutions
+[[bin]]
+name = "202201"
+path = "2022/01.rs"
+
+[[bin]]
+name = "202203"   # the third day
+path = "2022/03.rs"
+test = false
+
+[dependencies]
+advent = { path = "crates/advent" }
+
+[[bin]]
+name = "202204"
+path = "2022/04.rs"
+required-features = ["slow"]
+
+[profile.release]
+debug = true
+"#;
+
+    #[test]
+    fn bins_in_order() {
+        let m = manifest(MANIFEST);
+        assert_eq!(
+            m.bins(),
+            [
+                bin("202201", "2022/01.rs"),
+                bin("202203", "2022/03.rs"),
+                bin("202204", "2022/04.rs"),
+            ]
+        );
+        let m = manifest("bin = [{ name = \"a\", path = \"a.rs\" }, { name = \"b\" }]\n");
+        assert_eq!(m.bins(), [bin("a", "a.rs"), bin("b", "src/bin/b.rs")]);
+        assert_eq!(manifest("[package]\n").bins(), []);
+    }
+
+    #[test]
+    fn add_bin_preserves_layout() {
+        let mut m = manifest(MANIFEST);
+        assert!(m.add_bin(&bin("202202", "2022/02.rs")).unwrap());
+        assert!(!m.add_bin(&bin("202203", "2022/03.rs")).unwrap());
+        assert!(m.add_bin(&bin("202205", "2022/05.rs")).unwrap());
+        assert_eq!(
+            m.doc.to_string(),
+            r#"[package]
+name = "advent-bins"
+
+# Solutions
+[[bin]]
+name = "202201"
+path = "2022/01.rs"
+
+[[bin]]
+name = "202202"
+path = "2022/02.rs"
+
+[[bin]]
+name = "202203"   # the third day
+path = "2022/03.rs"
+test = false
+
+[dependencies]
+advent = { path = "crates/advent" }
+
+[[bin]]
+name = "202204"
+path = "2022/04.rs"
+required-features = ["slow"]
+
+[[bin]]
+name = "202205"
+path = "2022/05.rs"
+
+[profile.release]
+debug = true
+"#
+        );
+    }
+
+    #[test]
+    fn add_bin_first_and_empty() {
+        let mut m = manifest(MANIFEST);
+        assert!(m.add_bin(&bin("202125", "2021/25.rs")).unwrap());
+        assert!(m
+            .doc
+            .to_string()
+            .contains("# Solutions\n[[bin]]\nname = \"202201\"\npath = \"2022/01.rs\"\n\n"));
+        assert!(m.doc.to_string().starts_with(
+            "[package]\nname = \"advent-bins\"\n\n[[bin]]\nname = \"202125\"\npath = \"2021/25.rs\"\n"
+        ));
+
+        let mut m = manifest("[package]\nname = \"x\"\n");
+        assert!(m.add_bin(&bin("202301", "2023/01.rs")).unwrap());
+        assert_eq!(
+            m.doc.to_string(),
+            "[package]\nname = \"x\"\n\n[[bin]]\nname = \"202301\"\npath = \"2023/01.rs\"\n"
+        );
+    }
+
+    #[test]
+    fn add_bin_inline_array() {
+        let mut m = manifest("bin = [{ name = \"a\" }]\n");
+        assert_eq!(
+            m.add_bin(&bin("b", "b.rs")).unwrap_err().to_string(),
+            "failed to edit Cargo manifest, expected `[[bin]]` tables"
+        );
+    }
+
+    #[test]
+    fn remove_bin_preserves_layout() {
+        let mut m = manifest(MANIFEST);
+        assert_eq!(
+            m.remove_bin("202203").unwrap(),
+            Some(bin("202203", "2022/03.rs"))
+        );
+        assert_eq!(m.remove_bin("202203").unwrap(), None);
+        let text = m.doc.to_string();
+        assert!(!text.contains("202203"));
+        assert!(text.contains("[dependencies]\nadvent = { path = \"crates/advent\" }\n"));
+        assert!(text.contains("required-features = [\"slow\"]\n\n[profile.release]"));
+    }
+
+    #[test]
+    fn rename_bin_keeps_keys() {
+        let mut m = manifest(MANIFEST);
+        assert_eq!(
+            m.rename_bin("202203", &bin("202303", "2023/03.rs"))
+                .unwrap(),
+            Some(bin("202203", "2022/03.rs"))
+        );
+        assert!(m.doc.to_string().contains(
+            "[[bin]]\nname = \"202303\"   # the third day\npath = \"2023/03.rs\"\ntest = false\n"
+        ));
+        assert_eq!(m.rename_bin("202203", &bin("x", "x.rs")).unwrap(), None);
+        assert_eq!(
+            m.rename_bin("202201", &bin("202204", "2022/04.rs"))
+                .unwrap_err()
+                .to_string(),
+            "Cargo manifest already contains `202204` binary"
+        );
+    }
+}
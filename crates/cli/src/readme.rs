@@ -0,0 +1,322 @@
+//! Generate the progress section of the workspace README.
+//!
+//! The section is placed between the [`BEGIN`] and [`END`] markers and is
+//! replaced in full each time, so running the command again without any new
+//! answers or benchmarks leaves the README unchanged.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use anyhow::{bail, Context, Result};
+use argh::FromArgs;
+use time::OffsetDateTime;
+
+use crate::batch::human_time;
+use crate::calendar::{self, FIRST_YEAR};
+use crate::history::{self, Record};
+use crate::manifest::Manifest;
+use crate::table::{Cell, Table};
+use crate::{config, parse_args, print, submit, warning};
+
+const BEGIN: &str = "<!-- BEGIN cargo advent readme -->";
+const END: &str = "<!-- END cargo advent readme -->";
+
+/// Update the table of solutions, stars and benchmark times in the README.
+#[derive(Debug, FromArgs)]
+struct Opt {
+    /// the path to the README, defaults to `README.md` in the workspace
+    #[argh(option)]
+    path: Option<PathBuf>,
+
+    /// do not write the README, exit with a failure code if it is out of date
+    #[argh(switch)]
+    check: bool,
+}
+
+/// A puzzle with a solution or stars.
+#[derive(Debug, Clone, PartialEq)]
+struct Day {
+    day: u32,
+    /// The path to the solution relative to the README.
+    solution: Option<String>,
+    stars: u32,
+    /// The mean time of each part from the latest benchmark.
+    means: [Option<f64>; 2],
+}
+
+pub fn readme(args: &[String]) -> Result<()> {
+    let Opt { path, check } = parse_args("readme", args);
+
+    let workspace_dir = config::workspace_dir();
+    let path = path.unwrap_or_else(|| workspace_dir.join("README.md"));
+    let display = path.strip_prefix(&workspace_dir).unwrap_or(&path).display();
+    let text = fs::read_to_string(&path)
+        .with_context(|| format!("failed to read `{}`", path.display()))?;
+
+    let readme_dir = path.parent().unwrap_or(Path::new(""));
+    let years = years(&workspace_dir, readme_dir)?;
+    let section = render(&years, &config::get().base_url.value);
+    let updated = replace_section(&text, &section)?;
+
+    if updated == text {
+        print("Checked", format!("`{display}` is up to date"));
+    } else if check {
+        warning(
+            "Outdated",
+            format!("`{display}`, run `cargo advent readme` to update it"),
+        );
+        process::exit(1);
+    } else {
+        fs::write(&path, updated)
+            .with_context(|| format!("failed to write `{}`", path.display()))?;
+        print("Updated", format!("`{display}`"));
+    }
+    Ok(())
+}
+
+/// Returns each year with the released puzzles that have a solution or stars.
+fn years(workspace_dir: &Path, readme_dir: &Path) -> Result<Vec<(u32, Vec<Day>)>> {
+    let now = OffsetDateTime::now_utc();
+    let bins = Manifest::workspace()?.bins();
+    let records = history::load()?;
+
+    let mut years = Vec::new();
+    for y in FIRST_YEAR..=calendar::latest(now).year {
+        let mut days = Vec::new();
+        for d in 1..=calendar::last_day(y) {
+            if calendar::release_time(y, d)? > now {
+                break;
+            }
+            let solution = bins
+                .iter()
+                .find(|b| b.name == format!("{y:04}{d:02}"))
+                .map(|b| relative(&workspace_dir.join(&b.path), readme_dir));
+            let stars = submit::solved_parts(y, d)?;
+            if solution.is_none() && stars == 0 {
+                continue;
+            }
+            days.push(Day {
+                day: d,
+                solution,
+                stars,
+                means: means(&records, y, d),
+            });
+        }
+        if !days.is_empty() {
+            years.push((y, days));
+        }
+    }
+    Ok(years)
+}
+
+/// Returns the mean time of each part from the latest benchmark of the puzzle.
+fn means(records: &[Record], year: u32, day: u32) -> [Option<f64>; 2] {
+    let Some(record) = records
+        .iter()
+        .rev()
+        .find(|r| r.year == year && r.day == day)
+    else {
+        return [None; 2];
+    };
+    // The parse and shared solve steps are benchmarked before the parts
+    let mut parts = record
+        .benches
+        .iter()
+        .filter(|b| b.is_part())
+        .map(|b| b.stats.mean);
+    [parts.next(), parts.next()]
+}
+
+/// Returns the path relative to the directory for a Markdown link.
+fn relative(path: &Path, dir: &Path) -> String {
+    path.strip_prefix(dir)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Renders a Markdown table for each year with a row for each day.
+fn render(years: &[(u32, Vec<Day>)], base_url: &str) -> String {
+    let mut s = String::new();
+    for (year, days) in years {
+        let mut table = Table::new(&["Day", "Solution", "Stars", "Part 1", "Part 2"]);
+        for d in days {
+            let stars = (1..=2).map(|part| if d.stars >= part { '★' } else { '☆' });
+            let mut row = vec![
+                Cell::Plain(format!("[{}]({base_url}/{year}/day/{})", d.day, d.day)),
+                Cell::Plain(match &d.solution {
+                    Some(path) => format!("[{path}]({path})"),
+                    None => String::new(),
+                }),
+                Cell::Stars(stars.collect()),
+            ];
+            row.extend(
+                d.means
+                    .map(|m| Cell::Plain(m.map(human_time).unwrap_or_default())),
+            );
+            table.row(row);
+        }
+
+        let stars: u32 = days.iter().map(|d| d.stars).sum();
+        let solutions = days.iter().filter(|d| d.solution.is_some()).count();
+        let mut total = vec![
+            Cell::Plain("Total".into()),
+            Cell::Plain(format!("{solutions} solutions")),
+            Cell::Plain(format!("{stars} ⭐")),
+        ];
+        for i in 0..2 {
+            let means: Vec<_> = days.iter().filter_map(|d| d.means[i]).collect();
+            total.push(Cell::Plain(match means.is_empty() {
+                true => String::new(),
+                false => human_time(means.iter().sum()),
+            }));
+        }
+        table.bold_row(total);
+
+        s.push_str(&format!("\n### {year}\n\n{}", table.markdown()));
+    }
+    s
+}
+
+/// Replaces the text between the markers with the section.
+fn replace_section(text: &str, section: &str) -> Result<String> {
+    let (Some(begin), Some(end)) = (find_line(text, BEGIN), find_line(text, END)) else {
+        bail!("failed to find the section in the README, add the following where it should go\n\n{BEGIN}\n{END}");
+    };
+    if end < begin {
+        bail!("the end of the section in the README is before the beginning");
+    }
+    let before = &text[..begin + BEGIN.len()];
+    let after = &text[end..];
+    Ok(format!("{before}\n{section}\n{after}"))
+}
+
+/// Returns the offset of the first line that is exactly the marker, so that a
+/// marker mentioned in the text itself is not mistaken for it.
+fn find_line(text: &str, marker: &str) -> Option<usize> {
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if line.trim_end() == marker {
+            return Some(offset);
+        }
+        offset += line.len();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::batch::{Bench, Kind, Stats};
+
+    fn bench(name: &str, kind: Kind, mean: f64) -> Bench {
+        Bench {
+            name: name.into(),
+            kind,
+            stats: Stats {
+                samples: 1,
+                min: mean,
+                max: mean,
+                mean,
+                std_dev: 0.0,
+            },
+        }
+    }
+
+    fn record(day: u32, benches: Vec<Bench>) -> Record {
+        Record {
+            year: 2023,
+            day,
+            commit: None,
+            dirty: false,
+            time: OffsetDateTime::UNIX_EPOCH,
+            benches,
+        }
+    }
+
+    #[test]
+    fn means_from_latest_record() {
+        let records = [
+            record(
+                1,
+                vec![
+                    bench("Part 1", Kind::Part, 1.0),
+                    bench("Part 2", Kind::Part, 2.0),
+                ],
+            ),
+            record(2, vec![bench("Part 1", Kind::Part, 3.0)]),
+            record(
+                1,
+                vec![
+                    bench("Parse", Kind::Parse, 0.5),
+                    bench("Solve", Kind::Solve, 0.5),
+                    bench("Solve", Kind::Part, 4.0),
+                    bench("Part 2", Kind::Part, 5.0),
+                ],
+            ),
+        ];
+        assert_eq!(means(&records, 2023, 1), [Some(4.0), Some(5.0)]);
+        assert_eq!(means(&records, 2023, 2), [Some(3.0), None]);
+        assert_eq!(means(&records, 2023, 3), [None, None]);
+    }
+
+    #[test]
+    fn render_years() {
+        let days = vec![
+            Day {
+                day: 1,
+                solution: Some("2023/01.rs".into()),
+                stars: 2,
+                means: [Some(0.000_012_34), Some(0.001_5)],
+            },
+            Day {
+                day: 2,
+                solution: Some("2023/02.rs".into()),
+                stars: 1,
+                means: [Some(0.000_2), None],
+            },
+            Day {
+                day: 5,
+                solution: None,
+                stars: 2,
+                means: [None, None],
+            },
+        ];
+        assert_eq!(
+            render(&[(2023, days)], "https://adventofcode.com"),
+            "
+### 2023
+
+| Day | Solution | Stars | Part 1 | Part 2 |
+| --- | --- | --- | --- | --- |
+| [1](https://adventofcode.com/2023/day/1) | [2023/01.rs](2023/01.rs) | ★★ | 12.34 µs | 1.50 ms |
+| [2](https://adventofcode.com/2023/day/2) | [2023/02.rs](2023/02.rs) | ★☆ | 200.00 µs |  |
+| [5](https://adventofcode.com/2023/day/5) |  | ★★ |  |  |
+| **Total** | **2 solutions** | **5 ⭐** | **212.34 µs** | **1.50 ms** |
+"
+        );
+    }
+
+    #[test]
+    fn replace_section_idempotent() {
+        let text = format!("# Advent\n\n{BEGIN}\n{END}\n\n## License\n");
+        let once = replace_section(&text, "\n### 2023\n").unwrap();
+        assert_eq!(
+            once,
+            format!("# Advent\n\n{BEGIN}\n\n### 2023\n\n{END}\n\n## License\n")
+        );
+        assert_eq!(replace_section(&once, "\n### 2023\n").unwrap(), once);
+
+        // Only markers on a line of their own are replaced
+        let text = format!("Add `{BEGIN}`\n{text}");
+        assert_eq!(
+            replace_section(&text, "\n### 2023\n").unwrap(),
+            format!("Add `{BEGIN}`\n{once}")
+        );
+
+        let err = replace_section("# Advent\n", "").unwrap_err();
+        assert!(err.to_string().starts_with("failed to find the section"));
+    }
+}
@@ -0,0 +1,304 @@
+//! Parse selections of puzzle years and days.
+
+use std::cmp::Ordering;
+use std::ops::RangeInclusive;
+
+use crate::batch::Puzzle;
+use crate::calendar::{last_day, FIRST_YEAR};
+
+/// A selection of years or days, e.g. `2021`, `1..=10`, `1,3,5..8` or `all`.
+///
+/// A selection can also be relative to the current puzzle, e.g. `+1`, `-2`,
+/// `prev` or `next`, in which case it must be resolved using [`resolve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Select {
+    All,
+    Ranges(Vec<RangeInclusive<u32>>),
+    Relative(i64),
+}
+
+impl Select {
+    /// Returns the value if exactly one is selected.
+    pub fn single(&self) -> Option<u32> {
+        match self {
+            Self::Ranges(ranges) => match ranges.as_slice() {
+                [r] if r.start() == r.end() => Some(*r.start()),
+                _ => None,
+            },
+            Self::All | Self::Relative(_) => None,
+        }
+    }
+
+    pub fn contains(&self, n: u32) -> bool {
+        match self {
+            Self::All => true,
+            Self::Ranges(ranges) => ranges.iter().any(|r| r.contains(&n)),
+            Self::Relative(_) => unreachable!("relative selection must be resolved"),
+        }
+    }
+
+    /// Resolves a relative selection against the current value.
+    fn resolve(
+        self,
+        what: &str,
+        current: u32,
+        bounds: RangeInclusive<u32>,
+    ) -> Result<Self, String> {
+        let Self::Relative(offset) = self else {
+            return Ok(self);
+        };
+        match u32::try_from(i64::from(current) + offset) {
+            Ok(n) if bounds.contains(&n) => Ok(Self::Ranges(vec![n..=n])),
+            _ => Err(format!(
+                "{what} {current}{offset:+} is out of range {}..={}",
+                bounds.start(),
+                bounds.end()
+            )),
+        }
+    }
+
+    /// Checks that every selected value is within the bounds.
+    fn check(&self, what: &str, bounds: RangeInclusive<u32>) -> Result<(), String> {
+        let Self::Ranges(ranges) = self else {
+            return Ok(());
+        };
+        for r in ranges {
+            if bounds.contains(r.start()) && bounds.contains(r.end()) {
+                continue;
+            }
+            let selected = match r.start() == r.end() {
+                true => r.start().to_string(),
+                false => format!("{}..={}", r.start(), r.end()),
+            };
+            return Err(format!(
+                "{what} {selected} is out of range {}..={}",
+                bounds.start(),
+                bounds.end()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Resolves the year and day selections.
+///
+/// Missing and relative values are resolved using the current puzzle. Returns
+/// whether any value was resolved this way.
+pub fn resolve(
+    year: Option<Select>,
+    day: Option<Select>,
+    current: Puzzle,
+) -> Result<(Select, Select, bool), String> {
+    let implicit = year.as_ref().map_or(true, Select::is_relative)
+        || day.as_ref().map_or(true, Select::is_relative);
+
+    let year =
+        year.unwrap_or(Select::Relative(0))
+            .resolve("year", current.year, FIRST_YEAR..=u32::MAX)?;
+
+    let day = match day {
+        Some(Select::Relative(offset)) => {
+            let Some(y) = year.single() else {
+                return Err("a relative day requires a single year".into());
+            };
+            let current = if y == current.year {
+                current.day
+            } else {
+                last_day(y)
+            };
+            Select::Relative(offset).resolve("day", current, 1..=last_day(y))?
+        }
+        Some(day) => {
+            // Without a single year the days are checked against the longest
+            // calendar, which the first years have
+            let last = last_day(year.single().unwrap_or(FIRST_YEAR));
+            day.check("day", 1..=last)?;
+            day
+        }
+        None => match year.single() {
+            Some(y) if y == current.year => Select::Ranges(vec![current.day..=current.day]),
+            _ => return Err("a day must be given when not using the current year".into()),
+        },
+    };
+
+    Ok((year, day, implicit))
+}
+
+impl Select {
+    fn is_relative(&self) -> bool {
+        matches!(self, Self::Relative(_))
+    }
+}
+
+impl argh::FromArgValue for Select {
+    fn from_arg_value(value: &str) -> Result<Self, String> {
+        match value {
+            "all" => return Ok(Self::All),
+            "prev" => return Ok(Self::Relative(-1)),
+            "next" => return Ok(Self::Relative(1)),
+            _ => {}
+        }
+        if value.starts_with(['+', '-']) {
+            return value
+                .parse()
+                .map(Self::Relative)
+                .map_err(|_| format!("expected a relative offset, got `{value}`"));
+        }
+        value
+            .split(',')
+            .map(parse_range)
+            .collect::<Result<_, _>>()
+            .map(Self::Ranges)
+    }
+}
+
+fn parse_range(s: &str) -> Result<RangeInclusive<u32>, String> {
+    let parse = |n: &str| {
+        n.trim()
+            .parse::<u32>()
+            .map_err(|_| format!("expected a number, range, list or `all`, got `{s}`"))
+    };
+    let reversed = || format!("reversed range `{s}`, expected the start to come first");
+    if let Some((a, b)) = s.split_once("..=") {
+        let (a, b) = (parse(a)?, parse(b)?);
+        if a > b {
+            return Err(reversed());
+        }
+        Ok(a..=b)
+    } else if let Some((a, b)) = s.split_once("..") {
+        let (a, b) = (parse(a)?, parse(b)?);
+        match a.cmp(&b) {
+            Ordering::Less => Ok(a..=b - 1),
+            Ordering::Equal => Err(format!("empty range `{s}`")),
+            Ordering::Greater => Err(reversed()),
+        }
+    } else {
+        let n = parse(s)?;
+        Ok(n..=n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use argh::FromArgValue;
+
+    #[test]
+    fn select_parse() {
+        let parse = Select::from_arg_value;
+        assert_eq!(parse("all"), Ok(Select::All));
+        assert_eq!(parse("7"), Ok(Select::Ranges(vec![7..=7])));
+        assert_eq!(parse("1..=10"), Ok(Select::Ranges(vec![1..=10])));
+        assert_eq!(parse("1..10"), Ok(Select::Ranges(vec![1..=9])));
+        assert_eq!(
+            parse("2021,2022"),
+            Ok(Select::Ranges(vec![2021..=2021, 2022..=2022]))
+        );
+        assert_eq!(parse("1,3..=5"), Ok(Select::Ranges(vec![1..=1, 3..=5])));
+        assert_eq!(parse("+1"), Ok(Select::Relative(1)));
+        assert_eq!(parse("-2"), Ok(Select::Relative(-2)));
+        assert_eq!(parse("prev"), Ok(Select::Relative(-1)));
+        assert_eq!(parse("next"), Ok(Select::Relative(1)));
+        assert!(parse("+x").is_err());
+        assert!(parse("x").is_err());
+        assert!(parse("1..").is_err());
+        assert!(parse("1..0").is_err());
+        assert_eq!(parse("3..3"), Err("empty range `3..3`".into()));
+        assert_eq!(
+            parse("5..=3"),
+            Err("reversed range `5..=3`, expected the start to come first".into())
+        );
+        assert_eq!(
+            parse("2020,5..3"),
+            Err("reversed range `5..3`, expected the start to come first".into())
+        );
+        assert_eq!(parse("3..=3"), Ok(Select::Ranges(vec![3..=3])));
+    }
+
+    #[test]
+    fn select_single() {
+        let parse = |s| Select::from_arg_value(s).unwrap();
+        assert_eq!(parse("7").single(), Some(7));
+        assert_eq!(parse("7..=7").single(), Some(7));
+        assert_eq!(parse("7..=8").single(), None);
+        assert_eq!(parse("7,8").single(), None);
+        assert_eq!(parse("all").single(), None);
+    }
+
+    #[test]
+    fn resolve_defaults() {
+        let current = Puzzle {
+            year: 2023,
+            day: 17,
+        };
+        let parse = |s: &str| Select::from_arg_value(s).unwrap();
+        let resolve = |y: Option<&str>, d: Option<&str>| {
+            resolve(y.map(parse), d.map(parse), current)
+                .map(|(y, d, implicit)| (y.single(), d.single(), implicit))
+        };
+        assert_eq!(resolve(None, None), Ok((Some(2023), Some(17), true)));
+        assert_eq!(resolve(None, Some("3")), Ok((Some(2023), Some(3), true)));
+        assert_eq!(
+            resolve(Some("2023"), None),
+            Ok((Some(2023), Some(17), true))
+        );
+        assert_eq!(resolve(None, Some("+1")), Ok((Some(2023), Some(18), true)));
+        assert_eq!(
+            resolve(None, Some("prev")),
+            Ok((Some(2023), Some(16), true))
+        );
+        assert_eq!(
+            resolve(Some("prev"), Some("prev")),
+            Ok((Some(2022), Some(24), true))
+        );
+        assert_eq!(
+            resolve(Some("2021"), Some("4")),
+            Ok((Some(2021), Some(4), false))
+        );
+        assert_eq!(
+            resolve(None, Some("+9")),
+            Err("day 17+9 is out of range 1..=25".into())
+        );
+        assert_eq!(
+            resolve(None, Some("26")),
+            Err("day 26 is out of range 1..=25".into())
+        );
+        assert_eq!(
+            resolve(Some("2025"), Some("1,13")),
+            Err("day 13 is out of range 1..=12".into())
+        );
+        assert_eq!(
+            resolve(Some("2020"), Some("0..=3")),
+            Err("day 0..=3 is out of range 1..=25".into())
+        );
+        assert_eq!(
+            resolve(Some("all"), Some("20..=26")),
+            Err("day 20..=26 is out of range 1..=25".into())
+        );
+        assert_eq!(
+            resolve(Some("2025"), Some("12")),
+            Ok((Some(2025), Some(12), false))
+        );
+        assert_eq!(
+            resolve(Some("2021"), None),
+            Err("a day must be given when not using the current year".into())
+        );
+        assert_eq!(
+            resolve(Some("all"), Some("+1")),
+            Err("a relative day requires a single year".into())
+        );
+        assert_eq!(
+            super::resolve(Some(Select::All), Some(Select::All), current),
+            Ok((Select::All, Select::All, false))
+        );
+    }
+
+    #[test]
+    fn select_contains() {
+        let select = Select::from_arg_value("1,3..=5").unwrap();
+        let selected: Vec<_> = (1..=6).filter(|&n| select.contains(n)).collect();
+        assert_eq!(selected, [1, 3, 4, 5]);
+        assert!(Select::All.contains(25));
+    }
+}
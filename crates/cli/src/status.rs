@@ -0,0 +1,282 @@
+//! Report which puzzles have solutions, inputs and stars.
+
+use anyhow::Result;
+use argh::FromArgs;
+use time::OffsetDateTime;
+use yansi::Paint;
+
+use crate::calendar::{self, FIRST_YEAR};
+use crate::examples::strip_tags;
+use crate::http::Client;
+use crate::select::Select;
+use crate::table::{Cell, Style, Table};
+use crate::{config, parse_args, puzzles, submit};
+
+/// Show which puzzles have solutions, inputs and stars.
+#[derive(Debug, FromArgs)]
+struct Opt {
+    /// also fetch the stars from the personal leaderboard page for each year
+    #[argh(switch)]
+    online: bool,
+
+    /// the output style: grid or markdown
+    #[argh(option, default = "Output::Grid")]
+    output: Output,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Output {
+    Grid,
+    Markdown,
+}
+
+/// The status of a single puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Day {
+    day: u32,
+    solution: bool,
+    input: bool,
+    stars: u32,
+}
+
+pub fn status(year: &Select, day: &Select, args: &[String]) -> Result<()> {
+    let Opt { online, output } = parse_args("status", args);
+
+    let now = OffsetDateTime::now_utc();
+    let solutions = puzzles()?;
+    let client = if online { Some(Client::new()?) } else { None };
+
+    let mut years = Vec::new();
+    for y in (FIRST_YEAR..=calendar::latest(now).year).filter(|&y| year.contains(y)) {
+        // Only show years without any solutions if they were asked for
+        if *year == Select::All && !solutions.iter().any(|p| p.year == y) {
+            continue;
+        }
+        let stars = match &client {
+            Some(client) => Some(parse_self(&client.get(&format!("/{y}/leaderboard/self"))?)),
+            None => None,
+        };
+        let mut days = Vec::new();
+        for d in (1..=calendar::last_day(y)).filter(|&d| day.contains(d)) {
+            if calendar::release_time(y, d)? > now {
+                break;
+            }
+            let online = stars
+                .as_ref()
+                .and_then(|s| s.iter().find(|(n, _)| *n == d))
+                .map_or(0, |(_, n)| *n);
+            days.push(Day {
+                day: d,
+                solution: solutions.iter().any(|p| p.year == y && p.day == d),
+                input: config::get().input(y, d).exists(),
+                stars: submit::solved_parts(y, d)?.max(online),
+            });
+        }
+        years.push((y, days));
+    }
+
+    match output {
+        Output::Grid => {
+            let style = Style::stdout();
+            for (y, days) in &years {
+                print!("{}", render_grid(*y, days, style));
+            }
+            println!("{}", legend(style));
+        }
+        Output::Markdown => print!("{}", render_markdown(&years)),
+    }
+    Ok(())
+}
+
+/// Parses the stars for each day from the personal leaderboard page.
+///
+/// Each line of the page has the day followed by the time, rank and score for
+/// each part, with `-` for a part that is not solved.
+fn parse_self(html: &str) -> Vec<(u32, u32)> {
+    let mut stars = Vec::new();
+    for line in strip_tags(html).lines() {
+        let mut tokens = line.split_whitespace();
+        let Some(Ok(day)) = tokens.next().map(str::parse) else {
+            continue;
+        };
+        let times = tokens
+            .filter(|t| t.contains(':') || t.ends_with("24h"))
+            .count();
+        stars.push((day, times.min(2) as u32));
+    }
+    stars
+}
+
+/// Renders a calendar grid for the year.
+fn render_grid(year: u32, days: &[Day], style: Style) -> String {
+    let color = style == Style::Color;
+    let n = days.len();
+    let stars: u32 = days.iter().map(|d| d.stars).sum();
+    let solutions = days.iter().filter(|d| d.solution).count();
+    let inputs = days.iter().filter(|d| d.input).count();
+
+    let year = match color {
+        true => Paint::new(&year).bold().to_string(),
+        false => year.to_string(),
+    };
+    let mut s = format!(
+        "{year}  {stars}/{} ⭐  {solutions}/{n} solutions  {inputs}/{n} inputs\n",
+        2 * n
+    );
+    for row in days.chunks(5) {
+        let cells: Vec<_> = row.iter().map(|d| grid_cell(d, color)).collect();
+        s.push_str(&format!("  {}\n", cells.join("  ").trim_end()));
+    }
+    s.push('\n');
+    s
+}
+
+/// Renders the day, the stars and a flag for a missing solution or input.
+fn grid_cell(d: &Day, color: bool) -> String {
+    let stars: String = (1..=2)
+        .map(|part| if d.stars >= part { '★' } else { '☆' })
+        .collect();
+    let flag = match (d.solution, d.input) {
+        (false, _) => '✗',
+        (true, false) => '?',
+        (true, true) => ' ',
+    };
+    let day = format!("{:02}", d.day);
+    if !color {
+        return format!("{day} {stars}{flag}");
+    }
+    let day = match d.solution {
+        true => Paint::new(&day),
+        false => Paint::fixed(&day, 245),
+    };
+    let stars: String = stars
+        .chars()
+        .map(|c| match c {
+            '★' => Paint::yellow(&c).bold().to_string(),
+            c => Paint::fixed(&c, 238).to_string(),
+        })
+        .collect();
+    let flag = match flag {
+        '✗' => Paint::red(&flag).to_string(),
+        '?' => Paint::yellow(&flag).to_string(),
+        _ => flag.to_string(),
+    };
+    format!("{day} {stars}{flag}")
+}
+
+fn legend(style: Style) -> String {
+    let legend = "★ solved  ☆ unsolved  ✗ no solution  ? no input";
+    match style {
+        Style::Color => Paint::fixed(legend, 245).to_string(),
+        Style::Plain => legend.to_owned(),
+    }
+}
+
+/// Renders a Markdown table with a row for each year and a column for each
+/// day.
+fn render_markdown(years: &[(u32, Vec<Day>)]) -> String {
+    let max = years
+        .iter()
+        .flat_map(|(_, days)| days.iter().map(|d| d.day))
+        .max()
+        .unwrap_or(0);
+    let header: Vec<_> = ["Year".to_owned()]
+        .into_iter()
+        .chain((1..=max).map(|d| d.to_string()))
+        .chain(["⭐".to_owned()])
+        .collect();
+    let header: Vec<_> = header.iter().map(String::as_str).collect();
+
+    let mut table = Table::new(&header);
+    for (year, days) in years {
+        let mut row = vec![Cell::Plain(year.to_string())];
+        for d in 1..=max {
+            row.push(match days.iter().find(|x| x.day == d) {
+                Some(d) if d.solution || d.stars > 0 => {
+                    let stars = (1..=2).map(|part| if d.stars >= part { '★' } else { '☆' });
+                    Cell::Stars(stars.collect())
+                }
+                _ => Cell::Plain(String::new()),
+            });
+        }
+        let stars: u32 = days.iter().map(|d| d.stars).sum();
+        row.push(Cell::Plain(stars.to_string()));
+        table.row(row);
+    }
+    table.markdown()
+}
+
+impl argh::FromArgValue for Output {
+    fn from_arg_value(value: &str) -> Result<Self, String> {
+        match value {
+            "grid" => Ok(Self::Grid),
+            "markdown" => Ok(Self::Markdown),
+            _ => Err("expected `grid` or `markdown`".into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEADERBOARD_SELF: &str = include_str!("../fixtures/leaderboard-self.html");
+
+    fn days() -> Vec<Day> {
+        (1..=7)
+            .map(|day| Day {
+                day,
+                solution: day != 6,
+                input: day != 3,
+                stars: match day {
+                    1 | 2 => 2,
+                    3 => 1,
+                    _ => 0,
+                },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_self_page() {
+        assert_eq!(parse_self(LEADERBOARD_SELF), [(3, 1), (2, 2), (1, 2)]);
+        assert_eq!(parse_self("<p>You haven't collected any stars.</p>"), []);
+    }
+
+    #[test]
+    fn render_grid_year() {
+        assert_eq!(
+            render_grid(2023, &days(), Style::Plain),
+            "\
+2023  5/14 ⭐  6/7 solutions  6/7 inputs
+  01 ★★   02 ★★   03 ★☆?  04 ☆☆   05 ☆☆
+  06 ☆☆✗  07 ☆☆
+
+"
+        );
+    }
+
+    #[test]
+    fn legend_style() {
+        let legend_plain = "★ solved  ☆ unsolved  ✗ no solution  ? no input";
+        assert_eq!(legend(Style::Plain), legend_plain);
+        assert_eq!(
+            legend(Style::Color),
+            format!("\u{1b}[38;5;245m{legend_plain}\u{1b}[0m")
+        );
+    }
+
+    #[test]
+    fn render_markdown_years() {
+        let years = vec![(2022, days()), (2023, days()[..2].to_vec())];
+        assert_eq!(
+            render_markdown(&years),
+            "\
+| Year | 1 | 2 | 3 | 4 | 5 | 6 | 7 | ⭐ |
+| --- | --- | --- | --- | --- | --- | --- | --- | --- |
+| 2022 | ★★ | ★★ | ★☆ | ☆☆ | ☆☆ |  | ☆☆ | 5 |
+| 2023 | ★★ | ★★ |  |  |  |  |  | 4 |
+"
+        );
+    }
+}
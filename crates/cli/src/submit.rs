@@ -0,0 +1,496 @@
+//! Submit answers to Advent of Code and keep a local history of attempts.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use argh::FromArgs;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::batch::{Run, Summary, FAILED};
+use crate::config::Config;
+use crate::examples::strip_tags;
+use crate::http::Client;
+use crate::{cargo_command, check_input, config, parse_args, print, warning};
+
+/// Submit an answer for a puzzle.
+#[derive(Debug, FromArgs)]
+struct Opt {
+    /// the part to submit the answer for (1 or 2)
+    #[argh(positional)]
+    part: u32,
+
+    /// the answer, defaults to the result of running the solution
+    #[argh(positional)]
+    answer: Option<String>,
+}
+
+/// The result of submitting an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Response {
+    /// The answer was checked.
+    Verdict(Verdict),
+    /// An answer was submitted too recently.
+    RateLimited(Duration),
+    /// The part has already been solved or is not unlocked yet.
+    WrongLevel,
+    /// The response was not recognized, contains the text of the response.
+    Unknown(String),
+}
+
+/// Whether a submitted answer was correct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+}
+
+/// The history of submissions for a single puzzle.
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct History {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct Submission {
+    part: u32,
+    answer: String,
+    verdict: Verdict,
+    #[serde(with = "time::serde::rfc3339")]
+    time: OffsetDateTime,
+}
+
+pub fn submit(year: u32, day: u32, args: &[String]) -> Result<()> {
+    let Opt { part, answer } = parse_args("submit", args);
+    if !matches!(part, 1 | 2) {
+        bail!("part must be 1 or 2");
+    }
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => run_solution(year, day, part)?,
+    };
+
+    let path = history_path(year, day);
+    let mut history = History::load(&path)?;
+    if let Err(reason) = history.check(part, &answer) {
+        warning("Refused", reason);
+        process::exit(1);
+    }
+
+    print(
+        "Submitting",
+        format!("answer `{answer}` (year: {year:04}, day: {day:02}, part: {part})"),
+    );
+    let response = post(&Client::new()?, year, day, part, &answer)?;
+
+    match response {
+        Response::Verdict(verdict) => {
+            history.submissions.push(Submission {
+                part,
+                answer: answer.clone(),
+                verdict,
+                time: OffsetDateTime::now_utc(),
+            });
+            history.save(&path)?;
+            match verdict {
+                Verdict::Correct => print("Correct", format!("answer `{answer}` ⭐")),
+                verdict => {
+                    warning("Incorrect", format!("answer `{answer}` is {verdict}"));
+                    process::exit(1);
+                }
+            }
+        }
+        Response::RateLimited(wait) => {
+            warning(
+                "Wait",
+                format!(
+                    "an answer was submitted too recently, try again in {}",
+                    human_duration(wait)
+                ),
+            );
+            process::exit(1);
+        }
+        Response::WrongLevel => {
+            warning(
+                "Skipped",
+                format!("part {part} is already solved or not unlocked yet"),
+            );
+            process::exit(1);
+        }
+        Response::Unknown(text) => bail!("unrecognized response: {text}"),
+    }
+
+    Ok(())
+}
+
+/// Runs the solution and returns the answer for the given part.
+fn run_solution(year: u32, day: u32, part: u32) -> Result<String> {
+    check_input(year, day)?;
+
+    let bin_name = format!("{year:04}{day:02}");
+    let output = cargo_command()
+        .args(["run", "--release", "--quiet", "--features", "json"])
+        .args(["--bin", &bin_name])
+        .args(&config::get().cargo_args.value)
+        .args(["--", "--output", "json"])
+        .stderr(process::Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        bail!("failed to run `{bin_name}`");
+    }
+
+    let Summary::Run(runs) = serde_json::from_slice(&output.stdout)
+        .with_context(|| format!("failed to parse output of `{bin_name}`"))?
+    else {
+        bail!("expected runs in output of `{bin_name}`");
+    };
+    let run = runs
+        .into_iter()
+        .filter(Run::is_part)
+        .nth(part as usize - 1)
+        .with_context(|| format!("`{bin_name}` does not have part {part}"))?;
+    if run.result == FAILED {
+        bail!("part {part} of `{bin_name}` failed");
+    }
+    Ok(run.result)
+}
+
+/// Posts the answer and parses the response.
+fn post(client: &Client, year: u32, day: u32, part: u32, answer: &str) -> Result<Response> {
+    let form = format!("level={part}&answer={}", url_encode(answer));
+    let html = client.post(&format!("/{year}/day/{day}/answer"), &form)?;
+    Ok(Response::parse(&html))
+}
+
+/// Returns the number of parts with a correct answer in the history.
+pub fn solved_parts(year: u32, day: u32) -> Result<u32> {
+    Ok(History::load(&history_path(year, day))?.solved_parts())
+}
+
+/// Returns the correct answer for each part recorded for the profile in the
+/// configuration.
+pub fn correct_answers(config: &Config, year: u32, day: u32) -> Result<[Option<String>; 2]> {
+    let history = History::load(&config.input_sibling(year, day, "submissions.toml"))?;
+    Ok([1, 2].map(|part| history.correct_answer(part)))
+}
+
+fn history_path(year: u32, day: u32) -> PathBuf {
+    config::get().input_sibling(year, day, "submissions.toml")
+}
+
+impl Response {
+    fn parse(html: &str) -> Self {
+        let text = article_text(html);
+        if text.contains("That's the right answer") {
+            Self::Verdict(Verdict::Correct)
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Self::Verdict(Verdict::TooHigh)
+            } else if text.contains("your answer is too low") {
+                Self::Verdict(Verdict::TooLow)
+            } else {
+                Self::Verdict(Verdict::Incorrect)
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Self::RateLimited(parse_wait(&text).unwrap_or(Duration::from_secs(60)))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unknown(text)
+        }
+    }
+}
+
+/// Extracts the text of the `<article>` element, or the whole document if
+/// there is none, without any tags and with whitespace collapsed.
+fn article_text(html: &str) -> String {
+    let html = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    strip_tags(html)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parses the wait time from text like "You have 1m 23s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    let mut secs = 0;
+    for part in text[start..end].split_whitespace() {
+        let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        secs += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+fn human_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    match (secs / 60, secs % 60) {
+        (0, s) => format!("{s}s"),
+        (m, 0) => format!("{m}m"),
+        (m, s) => format!("{m}m {s}s"),
+    }
+}
+
+fn url_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            b => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => f.write_str("correct"),
+            Self::TooHigh => f.write_str("too high"),
+            Self::TooLow => f.write_str("too low"),
+            Self::Incorrect => f.write_str("incorrect"),
+        }
+    }
+}
+
+impl History {
+    fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => {
+                toml::from_str(&s).with_context(|| format!("failed to parse {}", path.display()))
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    fn correct_answer(&self, part: u32) -> Option<String> {
+        self.submissions
+            .iter()
+            .find(|s| s.part == part && s.verdict == Verdict::Correct)
+            .map(|s| s.answer.clone())
+    }
+
+    fn solved_parts(&self) -> u32 {
+        let solved = |part| {
+            self.submissions
+                .iter()
+                .any(|s| s.part == part && s.verdict == Verdict::Correct)
+        };
+        u32::from(solved(1)) + u32::from(solved(2))
+    }
+
+    /// Checks whether the answer is worth submitting, returning the reason if
+    /// it is not.
+    fn check(&self, part: u32, answer: &str) -> Result<(), String> {
+        let submissions = self.submissions.iter().filter(|s| s.part == part);
+
+        let mut lower: Option<i128> = None;
+        let mut upper: Option<i128> = None;
+        for s in submissions {
+            if s.answer == answer {
+                return Err(format!("answer `{answer}` is known to be {}", s.verdict));
+            }
+            match s.verdict {
+                Verdict::Correct => {
+                    return Err(format!(
+                        "part {part} was already solved with answer `{}`",
+                        s.answer
+                    ))
+                }
+                Verdict::TooHigh => {
+                    if let Ok(n) = s.answer.parse() {
+                        upper = Some(upper.map_or(n, |u| u.min(n)));
+                    }
+                }
+                Verdict::TooLow => {
+                    if let Ok(n) = s.answer.parse() {
+                        lower = Some(lower.map_or(n, |l| l.max(n)));
+                    }
+                }
+                Verdict::Incorrect => {}
+            }
+        }
+
+        if let Ok(n) = answer.parse::<i128>() {
+            if let Some(u) = upper.filter(|&u| n >= u) {
+                return Err(format!(
+                    "answer `{answer}` is too high, `{u}` was already too high"
+                ));
+            }
+            if let Some(l) = lower.filter(|&l| n <= l) {
+                return Err(format!(
+                    "answer `{answer}` is too low, `{l}` was already too low"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::http::tests::{response, serve, temp_dir, Request};
+
+    const CORRECT: &str = r#"<main>
+<article><p>That's the right answer!  You are <span class="day-success">one gold star</span> closer to saving Christmas. <a href="/2023/day/1#part2">[Continue to Part Two]</a></p></article>
+</main>"#;
+
+    const TOO_HIGH: &str = r#"<main>
+<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href="/2023/about">about page</a>, or you can ask for hints on the <a href="https://www.reddit.com/r/adventofcode/" target="_blank">subreddit</a>.  Please wait one minute before trying again. <a href="/2023/day/1">[Return to Day 1]</a></p></article>
+</main>"#;
+
+    const TOO_RECENT: &str = r#"<main>
+<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href="/2023/day/1">[Return to Day 1]</a></p></article>
+</main>"#;
+
+    const WRONG_LEVEL: &str = r#"<main>
+<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href="/2023/day/1">[Return to Day 1]</a></p></article>
+</main>"#;
+
+    fn submission(part: u32, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            part,
+            answer: answer.to_owned(),
+            verdict,
+            time: OffsetDateTime::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn response_parse() {
+        assert_eq!(
+            Response::parse(CORRECT),
+            Response::Verdict(Verdict::Correct)
+        );
+        assert_eq!(
+            Response::parse(TOO_HIGH),
+            Response::Verdict(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Response::parse(&TOO_HIGH.replace("too high", "too low")),
+            Response::Verdict(Verdict::TooLow)
+        );
+        assert_eq!(
+            Response::parse(&TOO_HIGH.replace("; your answer is too high", "")),
+            Response::Verdict(Verdict::Incorrect)
+        );
+        assert_eq!(
+            Response::parse(TOO_RECENT),
+            Response::RateLimited(Duration::from_secs(83))
+        );
+        assert_eq!(Response::parse(WRONG_LEVEL), Response::WrongLevel);
+        assert_eq!(
+            Response::parse("<p>Oops</p>"),
+            Response::Unknown("Oops".to_owned())
+        );
+    }
+
+    #[test]
+    fn parse_wait_units() {
+        let wait = |s: &str| parse_wait(&format!("You have {s} left to wait."));
+        assert_eq!(wait("45s"), Some(Duration::from_secs(45)));
+        assert_eq!(wait("5m 0s"), Some(Duration::from_secs(300)));
+        assert_eq!(wait("1h 2m 3s"), Some(Duration::from_secs(3723)));
+        assert_eq!(wait("soon"), None);
+    }
+
+    #[test]
+    fn history_check() {
+        let history = History {
+            submissions: vec![
+                submission(1, "500", Verdict::TooHigh),
+                submission(1, "100", Verdict::TooLow),
+                submission(1, "300", Verdict::TooHigh),
+                submission(1, "abc", Verdict::Incorrect),
+                submission(2, "42", Verdict::Correct),
+            ],
+        };
+        assert!(history.check(1, "200").is_ok());
+        assert!(history.check(1, "xyz").is_ok());
+        assert_eq!(
+            history.check(1, "abc").unwrap_err(),
+            "answer `abc` is known to be incorrect"
+        );
+        assert_eq!(
+            history.check(1, "400").unwrap_err(),
+            "answer `400` is too high, `300` was already too high"
+        );
+        assert_eq!(
+            history.check(1, "100").unwrap_err(),
+            "answer `100` is known to be too low"
+        );
+        assert_eq!(
+            history.check(1, "50").unwrap_err(),
+            "answer `50` is too low, `100` was already too low"
+        );
+        assert_eq!(
+            history.check(2, "43").unwrap_err(),
+            "part 2 was already solved with answer `42`"
+        );
+        assert_eq!(history.solved_parts(), 1);
+    }
+
+    #[test]
+    fn history_roundtrip() {
+        let history = History {
+            submissions: vec![submission(1, "123", Verdict::TooLow)],
+        };
+        let s = toml::to_string(&history).unwrap();
+        assert_eq!(
+            s,
+            "[[submission]]\npart = 1\nanswer = \"123\"\nverdict = \"too-low\"\ntime = \"1970-01-01T00:00:00Z\"\n"
+        );
+        let history: History = toml::from_str(&s).unwrap();
+        assert_eq!(history.submissions[0].verdict, Verdict::TooLow);
+    }
+
+    #[test]
+    fn url_encode_answer() {
+        assert_eq!(url_encode("123"), "123");
+        assert_eq!(url_encode("a,b c"), "a%2Cb%20c");
+    }
+
+    #[test]
+    fn post_local_server() {
+        let (base_url, server) = serve(vec![response("200 OK", &[], TOO_RECENT)]);
+        let client = Client::local(&base_url, temp_dir("submit"));
+
+        let response = post(&client, 2023, 1, 2, "12 34").unwrap();
+        assert_eq!(response, Response::RateLimited(Duration::from_secs(83)));
+
+        let requests = server.join().unwrap();
+        let Request { head, body } = &requests[0];
+        assert!(head.starts_with("POST /2023/day/1/answer HTTP/1.1\r\n"));
+        assert!(head.contains("Cookie: session=s3cr3t\r\n"));
+        assert_eq!(body, "level=2&answer=12%2034");
+    }
+}
@@ -0,0 +1,213 @@
+//! Render aligned tables for the terminal or as Markdown.
+
+use std::fmt::Write as _;
+use std::io::{self, IsTerminal};
+
+use yansi::Paint;
+
+/// A table cell and how it should be highlighted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cell {
+    Plain(String),
+    Good(String),
+    Bad(String),
+    Dim(String),
+    /// Puzzle stars, `★` for both parts, `☆` for one part and `·` for none.
+    Stars(String),
+}
+
+/// How to render a table for the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Highlight the cells using terminal colors and text attributes.
+    Color,
+    /// Render plain text without any escape sequences.
+    Plain,
+}
+
+/// A table with a header and rows, some of which may be bold.
+#[derive(Debug, Default)]
+pub struct Table {
+    header: Vec<Cell>,
+    rows: Vec<(Vec<Cell>, bool)>,
+}
+
+impl Style {
+    /// Returns the style for stdout, highlighted if it is a terminal.
+    pub fn stdout() -> Self {
+        match io::stdout().is_terminal() {
+            true => Self::Color,
+            false => Self::Plain,
+        }
+    }
+}
+
+impl Table {
+    pub fn new(header: &[&str]) -> Self {
+        Self {
+            header: header.iter().map(|h| Cell::Plain(h.to_string())).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn row(&mut self, row: Vec<Cell>) {
+        self.rows.push((row, false));
+    }
+
+    /// Adds a bold row, e.g. for a total.
+    pub fn bold_row(&mut self, row: Vec<Cell>) {
+        self.rows.push((row, true));
+    }
+
+    /// Renders the table for the terminal in the given style.
+    pub fn render(&self, style: Style) -> String {
+        let mut widths: Vec<_> = self.header.iter().map(Cell::width).collect();
+        for (row, _) in &self.rows {
+            for (w, cell) in widths.iter_mut().zip(row) {
+                *w = (*w).max(cell.width());
+            }
+        }
+
+        let color = style == Style::Color;
+        let mut s = String::new();
+        write_row(&mut s, &self.header, &widths, color, true);
+        for (row, bold) in &self.rows {
+            write_row(&mut s, row, &widths, color, *bold);
+        }
+        s
+    }
+
+    /// Renders the table as Markdown.
+    pub fn markdown(&self) -> String {
+        let line = |row: &[Cell], bold: bool| {
+            let cells: Vec<_> = (0..self.header.len())
+                .map(|i| match row.get(i).map(Cell::text) {
+                    Some("") | None => String::new(),
+                    Some(text) if bold => format!("**{}**", escape(text)),
+                    Some(text) => escape(text),
+                })
+                .collect();
+            format!("| {} |\n", cells.join(" | "))
+        };
+        let mut s = line(&self.header, false);
+        let rule: Vec<_> = self.header.iter().map(|_| "---").collect();
+        s.push_str(&format!("| {} |\n", rule.join(" | ")));
+        for (row, bold) in &self.rows {
+            s.push_str(&line(row, *bold));
+        }
+        s
+    }
+}
+
+fn write_row(s: &mut String, row: &[Cell], widths: &[usize], color: bool, bold: bool) {
+    let mut line = String::new();
+    for (i, (cell, w)) in row.iter().zip(widths).enumerate() {
+        if i != 0 {
+            line.push_str("  ");
+        }
+        let pad = " ".repeat(w - cell.width());
+        let text = cell.text();
+        if !color {
+            write!(line, "{text}{pad}").unwrap();
+            continue;
+        }
+        let painted = match cell {
+            Cell::Plain(_) => Paint::new(text),
+            Cell::Good(_) => Paint::green(text),
+            Cell::Bad(_) => Paint::red(text),
+            Cell::Dim(_) => Paint::fixed(text, 245),
+            Cell::Stars(_) => {
+                for c in text.chars() {
+                    match c {
+                        '★' => write!(line, "{}", Paint::yellow(&c).bold()),
+                        '☆' => write!(line, "{}", Paint::fixed(&c, 250)),
+                        c => write!(line, "{}", Paint::fixed(&c, 238)),
+                    }
+                    .unwrap();
+                }
+                line.push_str(&pad);
+                continue;
+            }
+        };
+        if bold {
+            write!(line, "{}{pad}", painted.bold()).unwrap();
+        } else {
+            write!(line, "{painted}{pad}").unwrap();
+        }
+    }
+    writeln!(s, "{}", line.trim_end()).unwrap();
+}
+
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+impl Cell {
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Plain(s) | Self::Good(s) | Self::Bad(s) | Self::Dim(s) | Self::Stars(s) => s,
+        }
+    }
+
+    /// Returns the width of the cell in a terminal, emoji are two columns.
+    pub fn width(&self) -> usize {
+        self.text()
+            .chars()
+            .map(|c| match c {
+                '⭐' | '\u{1f300}'..='\u{1faff}' => 2,
+                _ => 1,
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        let mut table = Table::new(&["Name", "Score"]);
+        table.row(vec![Cell::Plain("a|b".into()), Cell::Good("10".into())]);
+        table.row(vec![Cell::Plain("⭐".into())]);
+        table.bold_row(vec![Cell::Plain("Total".into()), Cell::Plain("10".into())]);
+        table
+    }
+
+    #[test]
+    fn render_plain() {
+        assert_eq!(
+            table().render(Style::Plain),
+            "\
+Name   Score
+a|b    10
+⭐
+Total  10
+"
+        );
+    }
+
+    #[test]
+    fn render_color() {
+        let mut table = Table::new(&["Day"]);
+        table.row(vec![Cell::Good("01".into())]);
+        table.bold_row(vec![Cell::Bad("02".into())]);
+        assert_eq!(
+            table.render(Style::Color),
+            "\u{1b}[1mDay\u{1b}[0m\n\u{1b}[32m01\u{1b}[0m\n\u{1b}[1;31m02\u{1b}[0m\n"
+        );
+    }
+
+    #[test]
+    fn render_markdown() {
+        assert_eq!(
+            table().markdown(),
+            "\
+| Name | Score |
+| --- | --- |
+| a\\|b | 10 |
+| ⭐ |  |
+| **Total** | **10** |
+"
+        );
+    }
+}
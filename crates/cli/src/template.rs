@@ -1,29 +1,204 @@
-use advent::prelude::*;
+//! Solution templates used by the `new` subcommand.
+//!
+//! A template is given by name or by path. Names are looked up in the
+//! workspace `templates/` directory first and then in the built-in templates,
+//! so a workspace can override or add to them.
+//!
+//! Templates can use the following placeholders.
+//!
+//! - `{ year }` the year, e.g. `2023`
+//! - `{ day }` the zero padded day, e.g. `01`
+//! - `{ day_number }` the day without padding, e.g. `1`
+//! - `{ bin }` the binary name, e.g. `202301`
+//! - `{ title }` the puzzle title, e.g. `Trebuchet?!`
+//! - `{ url }` the puzzle URL
 
-fn parse_input(input: &str) -> Vec<i64> {
-    todo!("parsing")
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+/// The built-in templates.
+const BUILTIN: &[(&str, &str)] = &[
+    ("default", include_str!("../templates/default.rs")),
+    ("device", include_str!("../templates/device.rs")),
+    ("grid", include_str!("../templates/grid.rs")),
+    ("intcode", include_str!("../templates/intcode.rs")),
+];
+
+/// Where a template was loaded from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Builtin(&'static str),
+    File(PathBuf),
+}
+
+/// The values for the placeholders.
+#[derive(Debug)]
+pub struct Vars {
+    pub year: u32,
+    pub day: u32,
+    pub title: Option<String>,
+    pub base_url: String,
 }
 
-fn default_input() -> Vec<i64> {
-    parse_input(include_input!({ year } / { day }))
+/// Loads the template with the given name or path.
+pub fn load(workspace_dir: &Path, name: &str) -> Result<(String, Source)> {
+    if name.contains(['/', '\\']) || name.ends_with(".rs") {
+        let path = PathBuf::from(name);
+        let template = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read template `{}`", path.display()))?;
+        return Ok((template, Source::File(path)));
+    }
+
+    let path = workspace_dir.join("templates").join(format!("{name}.rs"));
+    if path.exists() {
+        let template = fs::read_to_string(&path)?;
+        let path = path.strip_prefix(workspace_dir).unwrap_or(&path).to_owned();
+        return Ok((template, Source::File(path)));
+    }
+
+    match BUILTIN.iter().find(|(n, _)| *n == name) {
+        Some((name, template)) => Ok((template.to_string(), Source::Builtin(name))),
+        None => {
+            let names: Vec<_> = BUILTIN.iter().map(|(n, _)| *n).collect();
+            bail!(
+                "unknown template `{name}`, expected a path, a template in `templates/` or one of: {}",
+                names.join(", ")
+            )
+        }
+    }
 }
 
-fn part1(input: Vec<i64>) -> i64 {
-    todo!("part 1")
+/// Returns the names of the templates in the workspace `templates/` directory
+/// and the built-in templates.
+pub fn names(workspace_dir: &Path) -> Vec<String> {
+    let mut names: Vec<_> = BUILTIN.iter().map(|(n, _)| n.to_string()).collect();
+    if let Ok(entries) = fs::read_dir(workspace_dir.join("templates")) {
+        names.extend(entries.filter_map(|e| {
+            let path = e.ok()?.path();
+            match path.extension()?.to_str()? {
+                "rs" => Some(path.file_stem()?.to_str()?.to_owned()),
+                _ => None,
+            }
+        }));
+    }
+    names.sort();
+    names.dedup();
+    names
 }
 
-fn part2(input: Vec<i64>) -> i64 {
-    todo!("part 2")
+/// Renders the template, replacing each placeholder with its value.
+pub fn render(template: &str, vars: &Vars) -> String {
+    let Vars {
+        year,
+        day,
+        title,
+        base_url,
+    } = vars;
+    let values = [
+        ("year", format!("{year:04}")),
+        ("day", format!("{day:02}")),
+        ("day_number", day.to_string()),
+        ("bin", format!("{year:04}{day:02}")),
+        ("title", title.clone().unwrap_or_else(|| "Untitled".into())),
+        ("url", format!("{base_url}/{year}/day/{day}")),
+    ];
+    values.iter().fold(template.to_owned(), |s, (name, value)| {
+        s.replace(&format!("{{ {name} }}"), value)
+    })
 }
 
-fn main() {
-    let solution = advent::new(default_input).part(part1).part(part2).build();
-    solution.cli()
+/// Returns the modules declared in the source, e.g. `intcode` for
+/// `mod intcode;`.
+pub fn modules(source: &str) -> Vec<&str> {
+    source
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("mod ")?.strip_suffix(';'))
+        .collect()
 }
 
-#[test]
-fn default() {
-    let input = default_input();
-    assert_eq!(part1(input.clone()), 1);
-    assert_eq!(part2(input), 2);
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Builtin(name) => write!(f, "built-in template `{name}`"),
+            Self::File(path) => write!(f, "template `{}`", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::http::tests::temp_dir;
+
+    fn vars(title: Option<&str>) -> Vars {
+        Vars {
+            year: 2019,
+            day: 9,
+            title: title.map(String::from),
+            base_url: "https://adventofcode.com".into(),
+        }
+    }
+
+    #[test]
+    fn render_placeholders() {
+        let template = "//! Day { day_number }: { title }\n// { url }\nfn main() { include_input!({ year } / { day }); run(\"{ bin }\") }\n";
+        assert_eq!(
+            render(template, &vars(Some("Sensor Boost"))),
+            "//! Day 9: Sensor Boost\n// https://adventofcode.com/2019/day/9\nfn main() { include_input!(2019 / 09); run(\"201909\") }\n"
+        );
+        assert_eq!(render("{ title } { x }", &vars(None)), "Untitled { x }");
+    }
+
+    #[test]
+    fn builtin_templates_render() {
+        for (name, template) in BUILTIN {
+            let rendered = render(template, &vars(Some("Test")));
+            assert!(rendered.starts_with("//! Day 9: Test\n"), "{name}");
+            assert!(rendered.contains("include_input!(2019 / 09)"), "{name}");
+            for placeholder in ["{ year }", "{ day }", "{ day_number }", "{ title }"] {
+                assert!(!rendered.contains(placeholder), "{name}");
+            }
+        }
+        assert_eq!(modules(BUILTIN[0].1), Vec::<&str>::new());
+        assert_eq!(modules(BUILTIN[1].1), ["device"]);
+        assert_eq!(modules(BUILTIN[3].1), ["intcode"]);
+    }
+
+    #[test]
+    fn load_lookup() {
+        let dir = temp_dir("templates");
+        fs::create_dir_all(dir.join("templates")).unwrap();
+        fs::write(dir.join("templates/grid.rs"), "custom grid").unwrap();
+        fs::write(dir.join("templates/mine.rs"), "mine").unwrap();
+
+        let (template, source) = load(&dir, "grid").unwrap();
+        assert_eq!(template, "custom grid");
+        assert_eq!(source.to_string(), "template `templates/grid.rs`");
+
+        let (template, source) = load(&dir, "mine").unwrap();
+        assert_eq!(template, "mine");
+        assert_eq!(source, Source::File("templates/mine.rs".into()));
+
+        let (_, source) = load(&dir, "intcode").unwrap();
+        assert_eq!(source.to_string(), "built-in template `intcode`");
+
+        let path = dir.join("templates/mine.rs");
+        let (template, _) = load(&dir, path.to_str().unwrap()).unwrap();
+        assert_eq!(template, "mine");
+
+        assert_eq!(
+            names(&dir),
+            ["default", "device", "grid", "intcode", "mine"]
+        );
+
+        let err = load(&dir, "nope").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown template `nope`, expected a path, a template in `templates/` or one of: default, device, grid, intcode"
+        );
+    }
 }
@@ -0,0 +1,254 @@
+//! Wait for a puzzle to be released and then prepare everything for it.
+
+use std::io::{self, IsTerminal, Write};
+use std::thread;
+
+use anyhow::Result;
+use argh::FromArgs;
+use time::{Duration, OffsetDateTime};
+use yansi::Paint;
+
+use crate::{calendar, open, parse_args, print, scaffold};
+
+/// Wait for the puzzle to be released, then add the solution, download the
+/// input and extract the examples.
+#[derive(Debug, FromArgs)]
+struct Opt {
+    /// the template name or path for the solution, see `new`
+    #[argh(option, default = "String::from(\"default\")")]
+    template: String,
+
+    /// the number of seconds to wait after the release before making any
+    /// requests
+    #[argh(option, default = "3")]
+    delay: u32,
+
+    /// do not extract the example inputs from the puzzle page
+    #[argh(switch)]
+    no_examples: bool,
+
+    /// open the puzzle in the browser once it is ready
+    #[argh(switch)]
+    open: bool,
+}
+
+/// A source of the current time that can be replaced in tests.
+pub trait Clock {
+    fn now(&self) -> OffsetDateTime;
+    fn sleep(&self, duration: Duration);
+}
+
+/// The system clock.
+#[derive(Debug)]
+pub struct SystemClock;
+
+pub fn wait(year: u32, day: u32, args: &[String]) -> Result<()> {
+    let Opt {
+        template,
+        delay,
+        no_examples,
+        open: open_browser,
+    } = parse_args("wait", args);
+
+    wait_with(
+        &SystemClock,
+        year,
+        day,
+        Duration::seconds(delay.into()),
+        || {
+            scaffold(year, day, &template, !no_examples)?;
+            if open_browser {
+                open(year, day, &[])?;
+            }
+            Ok(())
+        },
+    )
+}
+
+/// Waits using the clock until the delay after the puzzle is released, then
+/// calls `ready`. If the puzzle is already released it is called immediately.
+fn wait_with<C: Clock>(
+    clock: &C,
+    year: u32,
+    day: u32,
+    delay: Duration,
+    ready: impl FnOnce() -> Result<()>,
+) -> Result<()> {
+    let release = calendar::release_time(year, day)?;
+    if clock.now() < release {
+        let until = release + delay;
+        let puzzle = format!("puzzle (year: {year:04}, day: {day:02})");
+        if io::stdout().is_terminal() {
+            wait_until(clock, until, |remaining| {
+                let header = Paint::new("Waiting").bold().green();
+                print!(
+                    "\r{header:>12} {} until {puzzle}\x1b[K",
+                    countdown(remaining)
+                );
+                io::stdout().flush().ok();
+            });
+            println!();
+        } else {
+            let remaining = countdown(until - clock.now());
+            print("Waiting", format!("{remaining} until {puzzle}"));
+            wait_until(clock, until, |_| {});
+        }
+        print("Released", puzzle);
+    }
+    ready()
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration.try_into().unwrap_or_default());
+    }
+}
+
+/// Sleeps until the given time, calling `tick` with the time remaining once
+/// for each second of the countdown.
+fn wait_until<C: Clock>(clock: &C, until: OffsetDateTime, mut tick: impl FnMut(Duration)) {
+    loop {
+        let remaining = until - clock.now();
+        if remaining <= Duration::ZERO {
+            break;
+        }
+        tick(remaining);
+        // Sleep to the next whole second so the countdown ticks evenly
+        let fraction = remaining - Duration::seconds(remaining.whole_seconds());
+        clock.sleep(match fraction.is_zero() {
+            true => Duration::SECOND,
+            false => fraction,
+        });
+    }
+}
+
+/// Formats the remaining time, rounding up to whole seconds, e.g. `01:02:03`
+/// or `2d 01:02:03`.
+fn countdown(remaining: Duration) -> String {
+    let mut secs = remaining.whole_seconds();
+    if remaining > Duration::seconds(secs) {
+        secs += 1;
+    }
+    let (days, h, m, s) = (secs / 86400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+    match days {
+        0 => format!("{h:02}:{m:02}:{s:02}"),
+        _ => format!("{days}d {h:02}:{m:02}:{s:02}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::{Cell, RefCell};
+
+    use time::macros::datetime;
+
+    /// A clock that only moves forward when slept.
+    struct FakeClock {
+        now: Cell<OffsetDateTime>,
+        sleeps: RefCell<Vec<Duration>>,
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> OffsetDateTime {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration);
+            self.sleeps.borrow_mut().push(duration);
+        }
+    }
+
+    fn clock(now: OffsetDateTime) -> FakeClock {
+        FakeClock {
+            now: Cell::new(now),
+            sleeps: RefCell::new(Vec::new()),
+        }
+    }
+
+    #[test]
+    fn wait_until_release() {
+        let release = calendar::release_time(2023, 1).unwrap();
+        let clock = clock(release - Duration::milliseconds(2500));
+        let mut ticks = Vec::new();
+        wait_until(&clock, release, |remaining| {
+            ticks.push(countdown(remaining))
+        });
+        assert_eq!(ticks, ["00:00:03", "00:00:02", "00:00:01"]);
+        assert_eq!(
+            *clock.sleeps.borrow(),
+            [
+                Duration::milliseconds(500),
+                Duration::SECOND,
+                Duration::SECOND
+            ]
+        );
+        assert_eq!(clock.now(), release);
+    }
+
+    #[test]
+    fn wait_until_released() {
+        let clock = clock(datetime!(2023-12-01 05:00:01 UTC));
+        let mut ticks = 0;
+        wait_until(&clock, datetime!(2023-12-01 05:00 UTC), |_| ticks += 1);
+        assert_eq!(ticks, 0);
+        assert!(clock.sleeps.borrow().is_empty());
+    }
+
+    #[test]
+    fn wait_with_before_release() {
+        let release = calendar::release_time(2023, 1).unwrap();
+        let clock = clock(release - Duration::milliseconds(2500));
+        let mut ready = None;
+        wait_with(&clock, 2023, 1, Duration::seconds(3), || {
+            ready = Some(clock.now());
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(ready, Some(release + Duration::seconds(3)));
+        assert_eq!(clock.sleeps.borrow().len(), 6);
+    }
+
+    #[test]
+    fn wait_with_after_release() {
+        let release = calendar::release_time(2023, 1).unwrap();
+        let clock = clock(release + Duration::HOUR);
+        let mut ready = None;
+        wait_with(&clock, 2023, 1, Duration::seconds(3), || {
+            ready = Some(clock.now());
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(ready, Some(release + Duration::HOUR));
+        assert!(clock.sleeps.borrow().is_empty());
+    }
+
+    #[test]
+    fn wait_with_error() {
+        let release = calendar::release_time(2023, 1).unwrap();
+        let clock = clock(release - Duration::SECOND);
+        let err = wait_with(&clock, 2023, 1, Duration::ZERO, || {
+            anyhow::bail!("failed to scaffold")
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "failed to scaffold");
+        assert_eq!(clock.now(), release);
+    }
+
+    #[test]
+    fn countdown_format() {
+        assert_eq!(countdown(Duration::seconds(59)), "00:00:59");
+        assert_eq!(countdown(Duration::milliseconds(59_001)), "00:01:00");
+        assert_eq!(countdown(Duration::seconds(3 * 3600 + 62)), "03:01:02");
+        assert_eq!(
+            countdown(datetime!(2023-12-01 05:00 UTC) - datetime!(2023-11-28 20:30 UTC)),
+            "2d 08:30:00"
+        );
+    }
+}
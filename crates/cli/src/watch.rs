@@ -0,0 +1,313 @@
+//! Re-run the tests, the solution or its benchmarks whenever it changes.
+//!
+//! The directories containing the watched files are watched using inotify
+//! rather than the files themselves, so that editors that save by replacing
+//! the file are picked up too.
+
+use std::collections::{BTreeSet, HashMap};
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::{self, Child, ExitStatus};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use argh::FromArgs;
+use inotify::{Inotify, WatchDescriptor, WatchMask};
+
+use crate::manifest::Manifest;
+use crate::{check_input, config, failure, parse_args, print, solution_command, template, warning};
+
+/// How long the files must be unchanged before the command is restarted.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// How often to check for changes and whether the command has finished.
+const POLL: Duration = Duration::from_millis(50);
+
+/// Set when the watcher is interrupted so that the command can be stopped.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Watch the solution and re-run a command whenever it changes.
+#[derive(Debug, FromArgs)]
+struct Opt {
+    /// the command to run on each change: test, run or bench, followed by any
+    /// arguments for it, defaults to test
+    #[argh(positional, greedy)]
+    args: Vec<String>,
+}
+
+/// The files that cause the command to be restarted when changed.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Paths {
+    /// Single files, e.g. the solution, its modules and the input.
+    files: BTreeSet<PathBuf>,
+    /// Directories where a change to any Rust file counts, e.g. the prelude.
+    dirs: BTreeSet<PathBuf>,
+}
+
+pub fn watch(year: u32, day: u32, args: &[String]) -> Result<()> {
+    let Opt { args } = parse_args("watch", args);
+    let (cmd, args) = match args.split_first() {
+        Some((cmd, rest)) if matches!(cmd.as_str(), "test" | "run" | "bench") => {
+            (cmd.as_str(), rest)
+        }
+        _ => ("test", &args[..]),
+    };
+
+    check_input(year, day)?;
+    handle_interrupts();
+
+    let workspace_dir = config::workspace_dir();
+    let bin_name = format!("{year:04}{day:02}");
+    let source = match Manifest::workspace()?
+        .bins()
+        .into_iter()
+        .find(|b| b.name == bin_name)
+    {
+        Some(bin) => workspace_dir.join(bin.path),
+        None => workspace_dir.join(format!("{year:04}/{day:02}.rs")),
+    };
+
+    let mut inotify = Inotify::init().context("failed to initialize inotify")?;
+    let mut buffer = [0; 4096];
+    let mut previous = Paths::default();
+    loop {
+        // The modules may have changed so the paths are found each time
+        let mut paths = Paths::default();
+        paths.add_source(&source, source.parent().unwrap());
+        paths.files.insert(config::get().input(year, day));
+        paths.dirs.insert(workspace_dir.join("crates/prelude/src"));
+        let watches = add_watches(&mut inotify, &paths)?;
+        if paths != previous {
+            let n = paths.files.len() + paths.dirs.len();
+            print(
+                "Watching",
+                format!("{n} paths for changes to puzzle (year: {year:04}, day: {day:02})"),
+            );
+        }
+
+        let started = Instant::now();
+        let mut child = Some(
+            solution_command(cmd, year, day, args)
+                .process_group(0)
+                .spawn()?,
+        );
+        loop {
+            if INTERRUPTED.load(Ordering::SeqCst) {
+                if let Some(mut child) = child {
+                    stop(&mut child)?;
+                }
+                process::exit(130);
+            }
+            if let Some(status) = child.as_mut().map(Child::try_wait).transpose()?.flatten() {
+                banner(cmd, status, started.elapsed());
+                child = None;
+            }
+            if changed(&mut inotify, &mut buffer, &watches, &paths)? {
+                // Wait for the changes to settle, e.g. a formatter on save
+                let mut last = Instant::now();
+                while last.elapsed() < DEBOUNCE {
+                    thread::sleep(POLL);
+                    if changed(&mut inotify, &mut buffer, &watches, &paths)? {
+                        last = Instant::now();
+                    }
+                }
+                if let Some(mut child) = child {
+                    if child.try_wait()?.is_none() {
+                        stop(&mut child)?;
+                        warning("Cancelled", format!("`{cmd}`, restarting after changes"));
+                    }
+                }
+                break;
+            }
+            thread::sleep(POLL);
+        }
+
+        for wd in watches.into_keys() {
+            // The directory may have been removed, which removes the watch
+            let _ = inotify.watches().remove(wd);
+        }
+        previous = paths;
+    }
+}
+
+impl Paths {
+    /// Adds the source file and the files for the modules it declares, which
+    /// are looked up in the given directory.
+    fn add_source(&mut self, path: &Path, modules_dir: &Path) {
+        self.files.insert(path.to_owned());
+        let Ok(source) = fs::read_to_string(path) else {
+            return;
+        };
+        for module in template::modules(&source) {
+            let dir = modules_dir.join(module);
+            let file = modules_dir.join(format!("{module}.rs"));
+            let mod_rs = dir.join("mod.rs");
+            match !file.exists() && mod_rs.exists() {
+                true => self.add_source(&mod_rs, &dir),
+                false => self.add_source(&file, &dir),
+            }
+        }
+    }
+
+    /// Returns whether a change to the path should restart the command.
+    fn matches(&self, path: &Path) -> bool {
+        self.files.contains(path)
+            || (path.extension() == Some(OsStr::new("rs"))
+                && self.dirs.iter().any(|dir| path.starts_with(dir)))
+    }
+
+    /// Returns the existing directories to watch, the parent of each file and
+    /// each of the directories recursively.
+    fn watch_dirs(&self) -> BTreeSet<PathBuf> {
+        let mut dirs: BTreeSet<_> = self
+            .files
+            .iter()
+            .filter_map(|f| f.parent())
+            .filter(|d| d.is_dir())
+            .map(Path::to_owned)
+            .collect();
+        let mut stack: Vec<_> = self.dirs.iter().filter(|d| d.is_dir()).cloned().collect();
+        while let Some(dir) = stack.pop() {
+            if let Ok(entries) = fs::read_dir(&dir) {
+                stack.extend(
+                    entries
+                        .filter_map(|e| Some(e.ok()?.path()))
+                        .filter(|p| p.is_dir()),
+                );
+            }
+            dirs.insert(dir);
+        }
+        dirs
+    }
+}
+
+fn add_watches(inotify: &mut Inotify, paths: &Paths) -> Result<HashMap<WatchDescriptor, PathBuf>> {
+    let mask = WatchMask::CLOSE_WRITE | WatchMask::MOVED_TO | WatchMask::CREATE | WatchMask::DELETE;
+    let mut watches = HashMap::new();
+    for dir in paths.watch_dirs() {
+        let wd = inotify
+            .watches()
+            .add(&dir, mask)
+            .with_context(|| format!("failed to watch `{}`", dir.display()))?;
+        watches.insert(wd, dir);
+    }
+    Ok(watches)
+}
+
+/// Reads the pending events, returning whether any watched path changed.
+fn changed(
+    inotify: &mut Inotify,
+    buffer: &mut [u8],
+    watches: &HashMap<WatchDescriptor, PathBuf>,
+    paths: &Paths,
+) -> Result<bool> {
+    let events = match inotify.read_events(buffer) {
+        Ok(events) => events,
+        Err(err) if err.kind() == io::ErrorKind::WouldBlock => return Ok(false),
+        Err(err) => return Err(err).context("failed to read inotify events"),
+    };
+    let mut changed = false;
+    for event in events {
+        if let (Some(dir), Some(name)) = (watches.get(&event.wd), event.name) {
+            changed |= paths.matches(&dir.join(name));
+        }
+    }
+    Ok(changed)
+}
+
+/// Stops the command along with any processes it started.
+fn stop(child: &mut Child) -> Result<()> {
+    // The child leads its own process group, so this reaches the solution
+    // binary that Cargo started too
+    // SAFETY: kill does not access any memory
+    unsafe { libc::kill(-(child.id() as libc::pid_t), libc::SIGTERM) };
+    child.wait()?;
+    Ok(())
+}
+
+/// Stops the command and exits on Ctrl-C, since it runs in its own process
+/// group and would not be interrupted otherwise.
+fn handle_interrupts() {
+    extern "C" fn on_interrupt(_: libc::c_int) {
+        INTERRUPTED.store(true, Ordering::SeqCst);
+    }
+    // SAFETY: the handler only stores to an atomic which is async-signal-safe
+    unsafe { libc::signal(libc::SIGINT, on_interrupt as libc::sighandler_t) };
+}
+
+/// Prints a single line with the outcome of the command.
+fn banner(cmd: &str, status: ExitStatus, elapsed: Duration) {
+    let message = format!("`{cmd}` in {elapsed:.2?}, waiting for changes");
+    match (status.success(), cmd) {
+        (true, "test") => print("Passed", message),
+        (true, _) => print("Finished", message),
+        (false, _) => failure("Failed", format!("{message} ({status})")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::http::tests::temp_dir;
+
+    #[test]
+    fn paths_follow_modules() {
+        let dir = temp_dir("watch");
+        fs::create_dir_all(dir.join("2019/device")).unwrap();
+        fs::write(
+            dir.join("2019/09.rs"),
+            "mod intcode;\nmod device;\n\nfn main() {}\n",
+        )
+        .unwrap();
+        fs::write(dir.join("2019/intcode.rs"), "mod ops;\n").unwrap();
+        fs::write(dir.join("2019/device/mod.rs"), "mod parse;\n").unwrap();
+
+        let mut paths = Paths::default();
+        paths.add_source(&dir.join("2019/09.rs"), &dir.join("2019"));
+        let files: Vec<_> = paths
+            .files
+            .iter()
+            .map(|f| f.strip_prefix(&dir).unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(
+            files,
+            [
+                "2019/09.rs",
+                "2019/device/mod.rs",
+                "2019/device/parse.rs",
+                "2019/intcode/ops.rs",
+                "2019/intcode.rs",
+            ]
+        );
+        // Only directories that exist can be watched
+        assert_eq!(
+            paths.watch_dirs(),
+            BTreeSet::from([dir.join("2019"), dir.join("2019/device")])
+        );
+    }
+
+    #[test]
+    fn paths_matches() {
+        let dir = temp_dir("watch-matches");
+        fs::create_dir_all(dir.join("prelude/src/grid")).unwrap();
+        let paths = Paths {
+            files: BTreeSet::from([dir.join("2023/01.rs"), dir.join("input/2023/01.txt")]),
+            dirs: BTreeSet::from([dir.join("prelude/src")]),
+        };
+        assert!(paths.matches(&dir.join("2023/01.rs")));
+        assert!(paths.matches(&dir.join("input/2023/01.txt")));
+        assert!(paths.matches(&dir.join("prelude/src/grid/mod.rs")));
+        assert!(!paths.matches(&dir.join("2023/02.rs")));
+        assert!(!paths.matches(&dir.join("prelude/src/.lib.rs.swp")));
+        assert_eq!(
+            paths.watch_dirs(),
+            BTreeSet::from([dir.join("prelude/src"), dir.join("prelude/src/grid")])
+        );
+    }
+}
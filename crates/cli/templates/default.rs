@@ -0,0 +1,33 @@
+//! Day { day_number }: { title }
+
+use advent::prelude::*;
+
+fn parse_input(input: &str) -> Vec<i64> {
+    todo!("parsing")
+}
+
+fn default_input() -> Vec<i64> {
+    parse_input(include_input!({ year } / { day }))
+}
+
+fn part1(input: Vec<i64>) -> i64 {
+    todo!("part 1")
+}
+
+fn part2(input: Vec<i64>) -> i64 {
+    todo!("part 2")
+}
+
+fn main() {
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
+    solution.cli()
+}
+
+advent::tests! {
+    input: "{ year }/{ day }",
+    default => [1, 2],
+}
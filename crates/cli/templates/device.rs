@@ -0,0 +1,39 @@
+//! Day { day_number }: { title }
+
+use advent::prelude::*;
+
+mod device;
+
+use device::{compute, parse_program, Program};
+
+fn default_input() -> Program {
+    parse_program(include_input!({ year } / { day }))
+}
+
+fn part1(prog: Program) -> usize {
+    let Program { ip, instrs } = prog;
+    let mut regs = [0; 6];
+    while regs[ip] < instrs.len() {
+        regs = compute(regs, instrs[regs[ip]]);
+        regs[ip] += 1;
+    }
+    todo!("part 1")
+}
+
+fn part2(prog: Program) -> usize {
+    todo!("part 2")
+}
+
+fn main() {
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
+    solution.cli()
+}
+
+advent::tests! {
+    input: "{ year }/{ day }",
+    default => [1, 2],
+}
@@ -0,0 +1,48 @@
+//! Day { day_number }: { title }
+
+use advent::prelude::*;
+
+fn parse_input(input: &str) -> HashMap<Vector2, Tile> {
+    parse_map(input, |c| match c {
+        '.' => Tile::Empty,
+        '#' => Tile::Wall,
+        c => panic!("unexpected character `{c}`"),
+    })
+}
+
+fn default_input() -> HashMap<Vector2, Tile> {
+    parse_input(include_input!({ year } / { day }))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    Wall,
+}
+
+const NORTH: Vector2 = vector![0, -1];
+const EAST: Vector2 = vector![1, 0];
+const SOUTH: Vector2 = vector![0, 1];
+const WEST: Vector2 = vector![-1, 0];
+
+fn part1(map: HashMap<Vector2, Tile>) -> usize {
+    todo!("part 1")
+}
+
+fn part2(map: HashMap<Vector2, Tile>) -> usize {
+    todo!("part 2")
+}
+
+fn main() {
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
+    solution.cli()
+}
+
+advent::tests! {
+    input: "{ year }/{ day }",
+    default => [1, 2],
+}
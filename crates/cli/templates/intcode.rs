@@ -0,0 +1,34 @@
+//! Day { day_number }: { title }
+
+mod intcode;
+
+use advent::prelude::*;
+use intcode::{parse_program, Computer};
+
+fn default_input() -> Vec<i64> {
+    parse_program(include_input!({ year } / { day }))
+}
+
+fn part1(input: Vec<i64>) -> i64 {
+    let mut computer = Computer::new(input);
+    todo!("part 1")
+}
+
+fn part2(input: Vec<i64>) -> i64 {
+    let mut computer = Computer::new(input);
+    todo!("part 2")
+}
+
+fn main() {
+    let solution = advent::new(default_input)
+        .part(part1)
+        .part(part2)
+        .answers(answers())
+        .build();
+    solution.cli()
+}
+
+advent::tests! {
+    input: "{ year }/{ day }",
+    default => [1, 2],
+}
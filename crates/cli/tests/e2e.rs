@@ -0,0 +1,278 @@
+//! End-to-end tests of the networked subcommands against the mock server.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::time::Duration;
+
+use advent_mock::{Config, Server};
+
+/// A temporary workspace with the CLI pointed at a mock server.
+struct Workspace {
+    dir: PathBuf,
+    server: Server,
+}
+
+/// The outcome of running the CLI.
+struct Output {
+    success: bool,
+    text: String,
+}
+
+impl Workspace {
+    fn new(name: &str) -> Self {
+        Self::with_config(name, Config::default())
+    }
+
+    fn with_config(name: &str, config: Config) -> Self {
+        let dir = env::temp_dir().join(format!("advent-e2e-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("Cargo.toml"),
+            "[package]\nname = \"e2e\"\nversion = \"0.0.0\"\nedition = \"2021\"\n",
+        )
+        .unwrap();
+        Self {
+            dir,
+            server: Server::start(config).unwrap(),
+        }
+    }
+
+    fn advent(&self, args: &[&str]) -> Output {
+        self.advent_with_session("mock-session", args)
+    }
+
+    fn advent_with_session(&self, session: &str, args: &[&str]) -> Output {
+        let output = Command::new(env!("CARGO_BIN_EXE_advent-cli"))
+            .args(args)
+            .current_dir(&self.dir)
+            .env("CARGO_WORKSPACE_DIR", &self.dir)
+            .env("HOME", self.dir.join("home"))
+            .env("ADVENT_BASE_URL", self.server.base_url())
+            .env("ADVENT_SESSION", session)
+            .env_remove("XDG_CACHE_HOME")
+            .env_remove("XDG_CONFIG_HOME")
+            .env_remove("ADVENT_PROFILE")
+            .output()
+            .unwrap();
+        Output {
+            success: output.status.success(),
+            text: format!(
+                "{}{}",
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            ),
+        }
+    }
+
+    fn read(&self, path: &str) -> String {
+        fs::read_to_string(self.dir.join(path)).unwrap()
+    }
+
+    fn write(&self, path: &str, contents: &str) {
+        let path = self.dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        self.dir.join(path).exists()
+    }
+
+    /// Returns each request the server received, e.g. `GET /2023/day/1 200`.
+    fn requests(&self) -> Vec<String> {
+        self.server
+            .requests()
+            .into_iter()
+            .map(|r| format!("{} {} {}", r.method, r.path, r.status))
+            .collect()
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn fixture(path: &str) -> String {
+    fs::read_to_string(Path::new(&Config::default().fixtures).join(path)).unwrap()
+}
+
+#[test]
+fn new_downloads_input_and_examples() {
+    let ws = Workspace::new("new");
+    let out = ws.advent(&["-y", "2023", "-d", "1", "new"]);
+    assert!(out.success, "{}", out.text);
+
+    assert!(ws.read("2023/01.rs").starts_with("//! Day 1: Trebuchet?!"));
+    assert!(ws.read("Cargo.toml").contains("name = \"202301\""));
+    assert_eq!(ws.read("input/2023/01.txt"), fixture("2023/01/input.txt"));
+    assert!(ws
+        .read("input/2023/01.example-1.txt")
+        .starts_with("1abc2\n"));
+    assert!(ws.read("input/checksums.txt").ends_with("  2023/01.txt\n"));
+    assert_eq!(
+        ws.requests(),
+        ["GET /2023/day/1 200", "GET /2023/day/1/input 200"]
+    );
+
+    // The puzzle page is revalidated using its ETag
+    let out = ws.advent(&["-y", "2023", "-d", "1", "examples"]);
+    assert!(out.success, "{}", out.text);
+    assert!(out.text.contains("Verified example input"), "{}", out.text);
+    assert_eq!(ws.requests()[2], "GET /2023/day/1 304");
+}
+
+#[test]
+fn wait_for_released_puzzle() {
+    let ws = Workspace::new("wait");
+    let out = ws.advent(&["-y", "2023", "-d", "2", "wait", "--no-examples"]);
+    assert!(out.success, "{}", out.text);
+    assert!(!out.text.contains("Waiting"), "{}", out.text);
+    assert!(ws.exists("2023/02.rs"));
+    assert_eq!(ws.read("input/2023/02.txt"), fixture("2023/02/input.txt"));
+    assert!(!ws.exists("input/2023/02.example-1.txt"));
+}
+
+#[test]
+fn input_requires_session() {
+    let ws = Workspace::new("session");
+    let out = ws.advent_with_session("expired", &["-y", "2023", "-d", "2", "wait"]);
+    assert!(!out.success);
+    assert!(
+        out.text
+            .contains("puzzle inputs differ by user, check that the session is set"),
+        "{}",
+        out.text
+    );
+    assert!(!ws.exists("input/2023/02.txt"));
+}
+
+#[test]
+fn inputs_refetch_when_rate_limited() {
+    let ws = Workspace::with_config(
+        "refetch",
+        Config {
+            min_interval: Duration::from_millis(500),
+            ..Config::default()
+        },
+    );
+    ws.write("input/2023/01.txt", "1abc2\n");
+    ws.write("input/2023/02.txt", "Game 1: 3 blue\n");
+
+    let out = ws.advent(&["-y", "2023", "inputs", "verify"]);
+    assert!(out.success, "{}", out.text);
+    assert!(out.text.contains("2 puzzle input(s) have no checksum"));
+
+    let out = ws.advent(&["-y", "2023", "inputs", "refetch"]);
+    assert!(out.success, "{}", out.text);
+    assert!(
+        out.text
+            .contains("Waiting 1s before retrying rate limited request"),
+        "{}",
+        out.text
+    );
+    assert!(out.text.contains("refetched 2 of 2 puzzle input(s)"));
+    assert_eq!(ws.read("input/2023/01.txt"), fixture("2023/01/input.txt"));
+    assert_eq!(ws.read("input/2023/02.txt"), fixture("2023/02/input.txt"));
+    assert_eq!(
+        ws.requests(),
+        [
+            "GET /2023/day/1/input 200",
+            "GET /2023/day/2/input 429",
+            "GET /2023/day/2/input 200"
+        ]
+    );
+
+    let out = ws.advent(&["-y", "2023", "inputs", "verify"]);
+    assert!(out.success, "{}", out.text);
+    assert!(out.text.contains("Verified 2 of 2 puzzle input(s)"));
+}
+
+#[test]
+fn submit_answers() {
+    let ws = Workspace::new("submit");
+    let submit = |part, answer| ws.advent(&["-y", "2023", "-d", "1", "submit", part, answer]);
+
+    let out = submit("1", "54601");
+    assert!(out.success, "{}", out.text);
+    assert!(
+        out.text.contains("Correct answer `54601` ⭐"),
+        "{}",
+        out.text
+    );
+    assert_eq!(ws.server.solved(2023, 1), 1);
+
+    let out = submit("2", "1");
+    assert!(!out.success);
+    assert!(out.text.contains("answer `1` is too low"), "{}", out.text);
+
+    // The known bounds are checked before anything is sent
+    let out = submit("2", "0");
+    assert!(!out.success);
+    assert!(out.text.contains("Refused"), "{}", out.text);
+
+    let out = submit("2", "54078");
+    assert!(!out.success);
+    assert!(
+        out.text
+            .contains("an answer was submitted too recently, try again in 1m"),
+        "{}",
+        out.text
+    );
+
+    let history = ws.read("input/2023/01.submissions.toml");
+    assert_eq!(history.matches("[[submission]]").count(), 2);
+    assert_eq!(ws.requests().len(), 3);
+}
+
+#[test]
+fn status_online() {
+    let ws = Workspace::new("status");
+    let out = ws.advent(&["-y", "2023", "status", "--online"]);
+    assert!(out.success, "{}", out.text);
+    assert!(out.text.starts_with("2023  5/50 ⭐"), "{}", out.text);
+    assert_eq!(ws.requests(), ["GET /2023/leaderboard/self 200"]);
+}
+
+#[test]
+fn leaderboard_requires_session() {
+    let ws = Workspace::new("leaderboard");
+    let out = ws.advent(&["-y", "2023", "leaderboard", "1001", "--output", "json"]);
+    assert!(out.success, "{}", out.text);
+    assert!(out.text.contains("\"name\": \"Ross\""), "{}", out.text);
+
+    let out = ws.advent_with_session("expired", &["-y", "2022", "leaderboard", "1001"]);
+    assert!(!out.success);
+    assert!(
+        out.text
+            .contains("failed with status 400, the session is not logged in"),
+        "{}",
+        out.text
+    );
+}
+
+#[test]
+fn doctor_checks_session() {
+    let ws = Workspace::new("doctor");
+    ws.write("2023/01.rs", "");
+    let out = ws.advent(&["doctor"]);
+    assert!(out.success, "{}", out.text);
+    assert!(out.text.contains("logged in as `example`"), "{}", out.text);
+    assert!(
+        out.text.contains("`2023/01.rs` does not have a `[[bin]]`"),
+        "{}",
+        out.text
+    );
+
+    let out = ws.advent_with_session("expired", &["doctor"]);
+    assert!(!out.success);
+    assert!(
+        out.text.contains("the session is not logged in"),
+        "{}",
+        out.text
+    );
+}
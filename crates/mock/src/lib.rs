@@ -0,0 +1,613 @@
+//! A mock Advent of Code server for testing the CLI without a network.
+//!
+//! The puzzle pages, inputs, answers and leaderboards are served from a
+//! directory of fixtures laid out as follows.
+//!
+//! ```text
+//! <year>/<day>/puzzle.html                the puzzle page
+//! <year>/<day>/puzzle-part2.html          the puzzle page once part one is solved
+//! <year>/<day>/input.txt                  the puzzle input
+//! <year>/<day>/answers.txt                the answer for each part, one per line
+//! <year>/leaderboard/self.html            the personal leaderboard page, which
+//!                                         has no stars if it is missing
+//! <year>/leaderboard/private/<id>.json    a private leaderboard
+//! ```
+//!
+//! Like the real site, requests without a user agent are refused and the
+//! inputs, answers and leaderboards need the session cookie. Requests closer
+//! together than the minimum interval are rate limited with a `429` and a
+//! `Retry-After` header, and an incorrect answer must be followed by a wait
+//! before the next one.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long to wait after an incorrect answer before giving another.
+const ANSWER_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How the server behaves.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The directory with the fixtures.
+    pub fixtures: PathBuf,
+    /// The session cookie that is accepted.
+    pub session: String,
+    /// The name of the user that the session is logged in as.
+    pub user: String,
+    /// Requests closer together than this are rate limited, zero to never
+    /// rate limit.
+    pub min_interval: Duration,
+    /// The `Retry-After` duration sent with rate limited responses.
+    pub retry_after: Duration,
+    /// Print each request to stderr.
+    pub log: bool,
+}
+
+/// A running server, which stops when the process exits.
+#[derive(Debug)]
+pub struct Server {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    handle: thread::JoinHandle<()>,
+}
+
+/// A request received by the server and the status it was answered with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub status: u16,
+}
+
+#[derive(Debug)]
+struct State {
+    config: Config,
+    requests: Vec<Request>,
+    last_request: Option<Instant>,
+    /// The number of parts solved for each puzzle.
+    solved: HashMap<(u32, u32), u32>,
+    /// When the next answer can be given after an incorrect one.
+    answer_after: Option<Instant>,
+}
+
+/// A parsed HTTP request.
+#[derive(Debug)]
+struct Incoming {
+    method: String,
+    path: String,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+#[derive(Debug)]
+struct Response {
+    status: u16,
+    headers: Vec<(&'static str, String)>,
+    body: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            fixtures: PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures")),
+            session: String::from("mock-session"),
+            user: String::from("example"),
+            min_interval: Duration::ZERO,
+            retry_after: Duration::from_secs(1),
+            log: false,
+        }
+    }
+}
+
+impl Server {
+    /// Starts the server on a free port on the local machine.
+    pub fn start(config: Config) -> io::Result<Self> {
+        Self::bind("127.0.0.1:0", config)
+    }
+
+    /// Starts the server on the given address.
+    pub fn bind(addr: impl ToSocketAddrs, config: Config) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(State {
+            config,
+            requests: Vec::new(),
+            last_request: None,
+            solved: HashMap::new(),
+            answer_after: None,
+        }));
+        let handle = {
+            let state = state.clone();
+            thread::spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    let state = state.clone();
+                    thread::spawn(move || handle(stream, &state));
+                }
+            })
+        };
+        Ok(Self {
+            addr,
+            state,
+            handle,
+        })
+    }
+
+    /// Returns the base URL to pass to the CLI, e.g. `http://127.0.0.1:8080`.
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Returns the requests received so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// Returns the number of parts solved for the puzzle.
+    pub fn solved(&self, year: u32, day: u32) -> u32 {
+        let state = self.state.lock().unwrap();
+        state.solved.get(&(year, day)).copied().unwrap_or(0)
+    }
+
+    /// Blocks until the server stops.
+    pub fn wait(self) {
+        self.handle.join().unwrap();
+    }
+}
+
+/// Answers a single request on the connection.
+fn handle(mut stream: TcpStream, state: &Mutex<State>) {
+    let response = match read_request(&stream) {
+        Ok(req) => {
+            let mut state = state.lock().unwrap();
+            let response = state.respond(&req);
+            if state.config.log {
+                eprintln!("{} {} {}", req.method, req.path, response.status);
+            }
+            state.requests.push(Request {
+                method: req.method,
+                path: req.path,
+                status: response.status,
+            });
+            response
+        }
+        Err(_) => Response::new(400, "Bad Request\n"),
+    };
+    let _ = stream.write_all(response.to_string().as_bytes());
+}
+
+fn read_request(stream: &TcpStream) -> io::Result<Incoming> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "invalid request");
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(invalid());
+    };
+    let method = method.to_owned();
+    let path = target.split('?').next().unwrap_or_default().to_owned();
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(invalid());
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let (name, value) = line.split_once(':').ok_or_else(invalid)?;
+        headers.push((name.trim().to_ascii_lowercase(), value.trim().to_owned()));
+    }
+
+    let length = headers
+        .iter()
+        .find(|(n, _)| n == "content-length")
+        .map_or(Ok(0), |(_, v)| v.parse().map_err(|_| invalid()))?;
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    Ok(Incoming {
+        method,
+        path,
+        headers,
+        body: String::from_utf8(body).map_err(|_| invalid())?,
+    })
+}
+
+impl State {
+    fn respond(&mut self, req: &Incoming) -> Response {
+        if req.header("user-agent").map_or(true, str::is_empty) {
+            return Response::new(
+                403,
+                "Please identify yourself via your User-Agent header.\n",
+            );
+        }
+
+        let now = Instant::now();
+        let limited = self
+            .last_request
+            .is_some_and(|last| now.duration_since(last) < self.config.min_interval);
+        if limited {
+            let secs = self.config.retry_after.as_secs_f64().ceil();
+            return Response::new(429, "Too Many Requests\n")
+                .header("Retry-After", secs.to_string());
+        }
+        self.last_request = Some(now);
+
+        let authorized = req.cookie("session") == Some(self.config.session.as_str());
+        let segments: Vec<_> = req.path.trim_matches('/').split('/').collect();
+        let response = match (req.method.as_str(), segments.as_slice()) {
+            ("GET", [year, "day", day]) => match puzzle(year, day) {
+                Some((year, day)) => self.page(year, day),
+                None => Response::not_found(),
+            },
+            ("GET", [year, "day", day, "input"]) => match puzzle(year, day) {
+                Some(_) if !authorized => Response::new(
+                    400,
+                    "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+                ),
+                Some((year, day)) => self.fixture(&format!("{year}/{day:02}/input.txt")),
+                None => Response::not_found(),
+            },
+            ("POST", [year, "day", day, "answer"]) => match puzzle(year, day) {
+                Some(_) if !authorized => Response::log_in(),
+                Some((year, day)) => self.answer(year, day, &req.body),
+                None => Response::not_found(),
+            },
+            ("GET", [_, "leaderboard", ..]) if !authorized => Response::log_in(),
+            ("GET", [year, "leaderboard", "self"]) => {
+                let path = format!("{year}/leaderboard/self.html");
+                match self.config.fixtures.join(&path).exists() {
+                    true => self.fixture(&path),
+                    false => self.no_stars(),
+                }
+            }
+            ("GET", [year, "leaderboard", "private", "view", file]) => {
+                match file.strip_suffix(".json") {
+                    Some(id) => self.fixture(&format!("{year}/leaderboard/private/{id}.json")),
+                    None => Response::not_found(),
+                }
+            }
+            _ => Response::not_found(),
+        };
+
+        // Pages can be revalidated using their ETag
+        if response.status != 200 || req.method != "GET" {
+            return response;
+        }
+        let etag = etag(&response.body);
+        match req.header("if-none-match") {
+            Some(tag) if tag == etag => Response::new(304, "").header("ETag", etag),
+            _ => response.header("ETag", etag),
+        }
+    }
+
+    /// Returns the puzzle page, which includes part two once part one is
+    /// solved.
+    fn page(&self, year: u32, day: u32) -> Response {
+        let solved = self.solved.get(&(year, day)).copied().unwrap_or(0);
+        let part2 = format!("{year}/{day:02}/puzzle-part2.html");
+        if solved > 0 && self.config.fixtures.join(&part2).exists() {
+            return self.fixture(&part2);
+        }
+        self.fixture(&format!("{year}/{day:02}/puzzle.html"))
+    }
+
+    /// Checks the answer in the form against the answers fixture.
+    fn answer(&mut self, year: u32, day: u32, form: &str) -> Response {
+        let path = self
+            .config
+            .fixtures
+            .join(format!("{year}/{day:02}/answers.txt"));
+        let Ok(answers) = fs::read_to_string(path) else {
+            return Response::not_found();
+        };
+        let field = |name: &str| {
+            form.split('&')
+                .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+                .map(url_decode)
+        };
+        let (Some(level), Some(answer)) = (field("level"), field("answer")) else {
+            return Response::new(400, "Bad Request\n");
+        };
+
+        let solved = self.solved.entry((year, day)).or_insert(0);
+        let now = Instant::now();
+        let message = if level != (*solved + 1).to_string() {
+            String::from(
+                "You don't seem to be solving the right level.  Did you already complete it?",
+            )
+        } else if let Some(wait) = self
+            .answer_after
+            .and_then(|t| t.checked_duration_since(now))
+        {
+            let secs = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+            let left = match (secs / 60, secs % 60) {
+                (0, s) => format!("{s}s"),
+                (m, s) => format!("{m}m {s}s"),
+            };
+            format!(
+                "You gave an answer too recently; you have to wait after submitting an \
+                 answer before trying again.  You have {left} left to wait."
+            )
+        } else {
+            let correct = answers.lines().nth(*solved as usize).unwrap_or_default();
+            if answer == correct.trim() {
+                *solved += 1;
+                String::from(
+                    "That's the right answer!  You are one gold star closer to restoring \
+                     snow operations.",
+                )
+            } else {
+                self.answer_after = Some(now + ANSWER_TIMEOUT);
+                let hint = match (answer.parse::<i64>(), correct.trim().parse::<i64>()) {
+                    (Ok(a), Ok(c)) if a > c => "  Curiously, your answer is too high.",
+                    (Ok(a), Ok(c)) if a < c => "  Curiously, your answer is too low.",
+                    _ => "",
+                };
+                format!(
+                    "That's not the right answer.{hint}  Please wait one minute before \
+                     trying again."
+                )
+            }
+        };
+        Response::new(
+            200,
+            format!("<!DOCTYPE html>\n<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>\n"),
+        )
+    }
+
+    /// Returns the personal leaderboard page for a year without any stars.
+    fn no_stars(&self) -> Response {
+        Response::new(
+            200,
+            format!(
+                "<!DOCTYPE html>\n<html><body>\n<header><div class=\"user\">{} <span class=\"star-count\">0*</span></div></header>\n<main>\n<article><p>You haven't collected any stars.</p></article>\n</main></body></html>\n",
+                self.config.user
+            ),
+        )
+    }
+
+    /// Returns the fixture at the path relative to the fixtures directory.
+    fn fixture(&self, path: &str) -> Response {
+        match fs::read_to_string(self.config.fixtures.join(path)) {
+            Ok(body) => Response::new(200, body),
+            Err(_) => Response::not_found(),
+        }
+    }
+}
+
+impl Incoming {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn cookie(&self, name: &str) -> Option<&str> {
+        self.header("cookie")?
+            .split(';')
+            .find_map(|c| c.trim().strip_prefix(name)?.strip_prefix('='))
+    }
+}
+
+impl Response {
+    fn new(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    fn not_found() -> Self {
+        Self::new(404, "404 Not Found\n")
+    }
+
+    fn log_in() -> Self {
+        Self::new(400, "<p>Please log in.</p>\n")
+    }
+
+    fn header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+}
+
+impl std::fmt::Display for Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reason = match self.status {
+            200 => "OK",
+            304 => "Not Modified",
+            400 => "Bad Request",
+            403 => "Forbidden",
+            404 => "Not Found",
+            429 => "Too Many Requests",
+            _ => "Unknown",
+        };
+        write!(f, "HTTP/1.1 {} {reason}\r\n", self.status)?;
+        for (name, value) in &self.headers {
+            write!(f, "{name}: {value}\r\n")?;
+        }
+        write!(
+            f,
+            "Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.body.len(),
+            self.body
+        )
+    }
+}
+
+/// Parses the year and day from the path segments, e.g. `2023` and `1`.
+fn puzzle(year: &str, day: &str) -> Option<(u32, u32)> {
+    let year = year.parse().ok()?;
+    let day = day.parse().ok().filter(|d| (1..=25).contains(d))?;
+    Some((year, day))
+}
+
+fn etag(body: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    format!("\"{:016x}\"", hasher.finish())
+}
+
+fn url_decode(s: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut iter = s.bytes();
+    while let Some(b) = iter.next() {
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex: Vec<_> = iter.by_ref().take(2).collect();
+                match std::str::from_utf8(&hex)
+                    .ok()
+                    .and_then(|h| u8::from_str_radix(h, 16).ok())
+                {
+                    Some(b) => bytes.push(b),
+                    None => bytes.extend([b'%'].iter().chain(&hex)),
+                }
+            }
+            b => bytes.push(b),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sends a raw request and returns the raw response.
+    fn raw(server: &Server, request: &str) -> String {
+        let mut stream = TcpStream::connect(server.addr).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    /// Sends a raw request and returns the status and body of the response.
+    fn send(server: &Server, request: &str) -> (u16, String) {
+        let response = raw(server, request);
+        let status = response[9..12].parse().unwrap();
+        let body = response.split_once("\r\n\r\n").unwrap().1.to_owned();
+        (status, body)
+    }
+
+    fn get(server: &Server, path: &str, session: &str) -> (u16, String) {
+        send(
+            server,
+            &format!(
+                "GET {path} HTTP/1.1\r\nUser-Agent: test\r\nCookie: session={session}\r\n\r\n"
+            ),
+        )
+    }
+
+    fn post_answer(server: &Server, level: u32, answer: &str) -> String {
+        let form = format!("level={level}&answer={answer}");
+        let (status, body) = send(
+            server,
+            &format!(
+                "POST /2023/day/1/answer HTTP/1.1\r\nUser-Agent: test\r\n\
+                 Cookie: session=mock-session\r\nContent-Length: {}\r\n\r\n{form}",
+                form.len()
+            ),
+        );
+        assert_eq!(status, 200);
+        body
+    }
+
+    #[test]
+    fn requires_user_agent_and_session() {
+        let server = Server::start(Config::default()).unwrap();
+        let (status, _) = send(&server, "GET /2023/day/1 HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 403);
+
+        let (status, body) = get(&server, "/2023/day/1", "");
+        assert_eq!(status, 200);
+        assert!(body.contains("--- Day 1: Trebuchet?! ---"));
+
+        let (status, body) = get(&server, "/2023/day/1/input", "nope");
+        assert_eq!(status, 400);
+        assert!(body.starts_with("Puzzle inputs differ by user."));
+        assert_eq!(
+            get(&server, "/2023/day/1/input", "mock-session"),
+            (200, "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n".into())
+        );
+        assert_eq!(get(&server, "/2023/leaderboard/self", "").0, 400);
+        let (status, body) = get(&server, "/2022/leaderboard/self", "mock-session");
+        assert_eq!(status, 200);
+        assert!(body.contains("<div class=\"user\">example "));
+        assert_eq!(get(&server, "/2023/day/26", "mock-session").0, 404);
+
+        let statuses: Vec<_> = server.requests().iter().map(|r| r.status).collect();
+        assert_eq!(statuses, [403, 200, 400, 200, 400, 200, 404]);
+    }
+
+    #[test]
+    fn rate_limits_requests() {
+        let server = Server::start(Config {
+            min_interval: Duration::from_millis(200),
+            ..Config::default()
+        })
+        .unwrap();
+        assert_eq!(get(&server, "/2023/day/1", "").0, 200);
+        let response = raw(
+            &server,
+            "GET /2023/day/1 HTTP/1.1\r\nUser-Agent: test\r\n\r\n",
+        );
+        assert!(response.starts_with("HTTP/1.1 429 Too Many Requests\r\nRetry-After: 1\r\n"));
+        thread::sleep(Duration::from_millis(200));
+        assert_eq!(get(&server, "/2023/day/1", "").0, 200);
+    }
+
+    #[test]
+    fn checks_answers() {
+        let server = Server::start(Config::default()).unwrap();
+        assert!(post_answer(&server, 2, "1").contains("solving the right level"));
+        assert!(post_answer(&server, 1, "60000").contains("your answer is too high"));
+        assert!(post_answer(&server, 1, "54601").contains("You have 1m 0s left to wait"));
+        assert_eq!(server.solved(2023, 1), 0);
+
+        server.state.lock().unwrap().answer_after = None;
+        assert!(post_answer(&server, 1, "54601").contains("That's the right answer"));
+        assert_eq!(server.solved(2023, 1), 1);
+        let (_, page) = get(&server, "/2023/day/1", "mock-session");
+        assert!(page.contains("--- Part Two ---"));
+    }
+
+    #[test]
+    fn revalidates_etag() {
+        let server = Server::start(Config::default()).unwrap();
+        let response = raw(
+            &server,
+            "GET /2023/day/2 HTTP/1.1\r\nUser-Agent: test\r\n\r\n",
+        );
+        let etag = response
+            .lines()
+            .find_map(|l| l.strip_prefix("ETag: "))
+            .unwrap();
+        let (status, body) = send(
+            &server,
+            &format!(
+                "GET /2023/day/2 HTTP/1.1\r\nUser-Agent: test\r\nIf-None-Match: {etag}\r\n\r\n"
+            ),
+        );
+        assert_eq!((status, body.as_str()), (304, ""));
+    }
+
+    #[test]
+    fn url_decode_form() {
+        assert_eq!(url_decode("a+b%2Cc%zz"), "a b,c%zz");
+    }
+}
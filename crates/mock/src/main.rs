@@ -0,0 +1,51 @@
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use advent_mock::{Config, Server};
+use argh::FromArgs;
+
+/// 🎄 Serve a mock Advent of Code from fixtures
+#[derive(Debug, FromArgs)]
+#[argh(example = "cargo run -p advent-mock -- --min-interval 1000")]
+struct Opt {
+    /// the address to listen on
+    #[argh(option, default = "String::from(\"127.0.0.1:8080\")")]
+    addr: String,
+
+    /// the directory with the fixtures, defaults to the fixtures in this crate
+    #[argh(option)]
+    fixtures: Option<PathBuf>,
+
+    /// the session cookie to accept
+    #[argh(option, default = "Config::default().session")]
+    session: String,
+
+    /// rate limit requests closer together than this many milliseconds
+    #[argh(option, default = "0")]
+    min_interval: u64,
+}
+
+fn main() -> io::Result<()> {
+    let Opt {
+        addr,
+        fixtures,
+        session,
+        min_interval,
+    } = argh::from_env();
+
+    let defaults = Config::default();
+    let server = Server::bind(
+        addr,
+        Config {
+            fixtures: fixtures.unwrap_or(defaults.fixtures),
+            session,
+            min_interval: Duration::from_millis(min_interval),
+            log: true,
+            ..defaults
+        },
+    )?;
+    eprintln!("Listening on {}", server.base_url());
+    server.wait();
+    Ok(())
+}
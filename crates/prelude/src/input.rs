@@ -0,0 +1,55 @@
+//! Load puzzle inputs at runtime.
+//!
+//! Inputs are read from the workspace instead of being embedded using
+//! `include_str!` so that solutions still compile when the private inputs are
+//! not checked out.
+//!
+//! By default the input for a puzzle is read from `input/{year}/{day}.txt`.
+//! The directory and the path within it can be changed at runtime using the
+//! `ADVENT_INPUT_DIR` and `ADVENT_INPUT_PATH` environment variables, which is
+//! how `cargo advent` passes on its configuration.
+//!
+//! The workspace build script sets `advent_input = "{year}/{day}"` for each
+//! input that exists, which is used to ignore the tests for missing inputs.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Loads the puzzle input for the given year and day from the workspace, used
+/// by the [`include_input!`][crate::include_input] macro.
+///
+/// The input is only read once, subsequent calls return the cached input.
+pub fn load_input(
+    cell: &'static OnceLock<String>,
+    workspace_dir: &str,
+    year: &str,
+    day: &str,
+) -> &'static str {
+    cell.get_or_init(|| {
+        let path = input_path(workspace_dir, year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                let path = path.strip_prefix(workspace_dir).unwrap_or(&path);
+                panic!(
+                    "missing puzzle input `{}`, try running `cargo advent` to download it",
+                    path.display()
+                )
+            }
+            Err(err) => panic!("failed to read puzzle input `{}`: {err}", path.display()),
+        }
+    })
+}
+
+/// Returns the path to the puzzle input.
+fn input_path(workspace_dir: &str, year: &str, day: &str) -> PathBuf {
+    let dir = match env::var_os("ADVENT_INPUT_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(workspace_dir).join("input"),
+    };
+    let path = env::var("ADVENT_INPUT_PATH").unwrap_or_else(|_| String::from("{year}/{day}.txt"));
+    dir.join(path.replace("{year}", year).replace("{day}", day))
+}
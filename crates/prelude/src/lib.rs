@@ -1,3 +1,5 @@
+mod input;
+
 pub use std::cmp;
 pub use std::cmp::{max, min, Ordering, Reverse};
 pub use std::collections::{BTreeMap, BTreeSet, BinaryHeap, VecDeque};
@@ -16,20 +18,24 @@ pub use regex_macro::regex;
 pub use then::Some as _;
 pub use vectrix::{vector, Matrix, Vector};
 
+pub use crate::input::load_input;
+
 pub type Vector2 = vectrix::Vector<i64, 2>;
 pub type Vector3 = vectrix::Vector<i64, 3>;
 pub type Vector4 = vectrix::Vector<i64, 4>;
 
+/// Loads the puzzle input for the given year and day from the `input/`
+/// directory in the workspace, see [`load_input`] for how to change this.
 #[macro_export]
 macro_rules! include_input {
     ($year:literal / $day:literal) => {{
-        include_str!(concat!(
-            "../input/",
+        static INPUT: ::std::sync::OnceLock<::std::string::String> = ::std::sync::OnceLock::new();
+        $crate::load_input(
+            &INPUT,
+            env!("CARGO_MANIFEST_DIR"),
             stringify!($year),
-            "/",
             stringify!($day),
-            ".txt"
-        ))
+        )
     }};
 }
 